@@ -0,0 +1,74 @@
+use crate::Part;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// The answer of a puzzle part, independent of the number type a day computes with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u128),
+    /// A fraction, e.g. of a day parameter beyond the puzzle.
+    Decimal(f64),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Decimal(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value.into())
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u128)
+    }
+}
+
+impl From<f64> for Answer {
+    fn from(value: f64) -> Self {
+        Answer::Decimal(value)
+    }
+}
+
+/// The error of a part that is not solved yet, e.g. of a freshly created day. The runner reports
+/// such parts instead of failing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotSolved {
+    pub day: u8,
+    pub part: Part,
+}
+
+impl Display for NotSolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "part {} of day {} is not solved yet",
+            self.part, self.day
+        )
+    }
+}
+
+impl Error for NotSolved {}
@@ -0,0 +1,92 @@
+use crate::{default_inputs, logging, open_inputs, read_inputs, Part, Puzzle};
+use anyhow::Context;
+use clap::{ArgAction, CommandFactory, FromArgMatches, Parser};
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+/// The arguments of a day binary.
+#[derive(Parser)]
+struct DayCli {
+    /// Input file(s), `-` reads the standard input, the data files of the day if omitted
+    #[arg(short, long)]
+    input: Vec<PathBuf>,
+    /// Puzzle part (`1` or `2`), both parts if omitted
+    #[arg(short, long)]
+    part: Option<Part>,
+    /// Day specific parameter, e.g. `blinks=25` for day 11
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// Check the input for problems instead of solving the puzzle
+    #[arg(long, conflicts_with_all = ["part", "params"])]
+    validate: bool,
+    /// Log more details to the standard error, repeat for debug and trace output
+    #[arg(short, long, action = ArgAction::Count)]
+    verbose: u8,
+    /// Show progress bars for long searches
+    #[arg(long)]
+    progress: bool,
+}
+
+/// Parses a `NAME=VALUE` parameter argument.
+pub fn parse_param(s: &str) -> anyhow::Result<(String, String)> {
+    let (name, value) = s.split_once('=').context("parameter must be NAME=VALUE")?;
+    Ok((name.to_string(), value.to_string()))
+}
+
+// returns false if the input has problems
+fn run(puzzle: &dyn Puzzle, crate_dir: &Path, cli: DayCli) -> anyhow::Result<bool> {
+    logging::init(cli.verbose, cli.progress);
+    let inputs = if cli.input.is_empty() {
+        default_inputs(puzzle, crate_dir)
+    } else {
+        cli.input
+    };
+    if cli.validate {
+        let problems = puzzle.validate(&read_inputs(&inputs)?);
+        for problem in &problems {
+            println!("{}", problem);
+        }
+        if problems.is_empty() {
+            println!("The input is valid");
+        }
+        return Ok(problems.is_empty());
+    }
+    let parsed_input = puzzle
+        .parse_reader(&mut open_inputs(&inputs)?)
+        .context("could not parse the input")?;
+    let parts = cli.part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    for part in parts {
+        let solution = puzzle
+            .solve(parsed_input.as_ref(), part, &cli.params)
+            .with_context(|| format!("part {} failed", part))?;
+        println!("Solution {}: {}", part, solution);
+    }
+    Ok(true)
+}
+
+/// The `main` of a day binary, `crate_dir` is the directory of the day crate holding its data files.
+pub fn main<P>(puzzle: &dyn Puzzle, crate_dir: P) -> ExitCode
+where
+    P: AsRef<Path>,
+{
+    let command = DayCli::command()
+        .name(format!("day-{:02}", puzzle.day()))
+        .about(format!(
+            "Solves the Advent of Code 2024 puzzle of day {}",
+            puzzle.day()
+        ));
+    let cli = match DayCli::from_arg_matches(&command.get_matches()) {
+        Ok(cli) => cli,
+        Err(err) => err.exit(),
+    };
+    match run(puzzle, crate_dir.as_ref(), cli) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Error: {:?}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
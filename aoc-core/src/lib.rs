@@ -0,0 +1,223 @@
+mod answer;
+pub mod cli;
+mod location;
+pub mod logging;
+pub mod memory;
+mod puzzle;
+mod validate;
+
+pub use answer::{Answer, NotSolved};
+pub use location::Location;
+pub use puzzle::Puzzle;
+pub use validate::{check_grid, check_numbers, Problem};
+
+use anyhow::{bail, Context};
+use std::{
+    env,
+    fmt::{self, Display},
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The input file name that stands for the standard input.
+pub const STDIN: &str = "-";
+
+/// Environment variable with a directory that replaces the workspace root when looking up
+/// the data files of a day, e.g. `$AOC_INPUT_DIR/day-05/data/data_rules_1`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("part must be 1 or 2, got `{}`", s),
+        }
+    }
+}
+
+/// Day specific settings of a puzzle, e.g. the number of blinks on day 11.
+pub trait Parameters: Default {
+    /// Sets a parameter from its textual `name=value` representation.
+    fn set(&mut self, name: &str, value: &str) -> anyhow::Result<()>;
+}
+
+impl Parameters for () {
+    fn set(&mut self, name: &str, _value: &str) -> anyhow::Result<()> {
+        bail!("unknown parameter `{}`", name)
+    }
+}
+
+/// A puzzle day: the input is parsed once into a typed model that both parts operate on.
+pub trait Solution {
+    const DAY: u8;
+    /// The data files of the day crate, relative to the crate directory.
+    const INPUT_FILES: &'static [&'static str] = &["data/data_1"];
+
+    type Input;
+    type Params: Parameters;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+    /// Parses the input from a reader, e.g. a file opened by [`open_inputs`]. Reads the whole
+    /// input and ends it like [`read_inputs`] unless a day parses it line by line.
+    fn parse_reader<R>(reader: R) -> anyhow::Result<Self::Input>
+    where
+        R: Read,
+    {
+        let input = io::read_to_string(reader)?;
+        Self::parse(&(input.trim_end().to_string() + "\n"))
+    }
+
+    /// A random valid input of the given size, e.g. lines or grid rows, the same for the same seed.
+    fn generate(seed: u64, size: usize) -> String;
+
+    /// Checks the input against the puzzle format and invariants without solving it and
+    /// reports all problems. The input has to parse as well if no problems are reported.
+    fn validate(_input: &str) -> Vec<Problem> {
+        Vec::new()
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> anyhow::Result<Answer>;
+    fn part2(input: &Self::Input, params: &Self::Params) -> anyhow::Result<Answer>;
+
+    fn solve(input: &Self::Input, part: Part, params: &Self::Params) -> anyhow::Result<Answer> {
+        match part {
+            Part::One => Self::part1(input, params),
+            Part::Two => Self::part2(input, params),
+        }
+    }
+}
+
+/// The data files of a puzzle, inside of `crate_dir` unless [`INPUT_DIR_VAR`] is set.
+pub fn default_inputs<P>(puzzle: &dyn Puzzle, crate_dir: P) -> Vec<PathBuf>
+where
+    P: AsRef<Path>,
+{
+    let base_dir = match env::var_os(INPUT_DIR_VAR) {
+        Some(input_dir) => PathBuf::from(input_dir).join(format!("day-{:02}", puzzle.day())),
+        None => crate_dir.as_ref().to_path_buf(),
+    };
+    puzzle
+        .input_files()
+        .iter()
+        .map(|file| base_dir.join(file))
+        .collect()
+}
+
+/// Reads the input files of a puzzle, [`STDIN`] reads the standard input.
+/// Multiple files are joined with a blank line in between.
+pub fn read_inputs<P>(filenames: &[P]) -> anyhow::Result<String>
+where
+    P: AsRef<Path>,
+{
+    let mut contents = Vec::new();
+    for filename in filenames {
+        let filename = filename.as_ref();
+        let content = if filename == Path::new(STDIN) {
+            io::read_to_string(io::stdin()).context("could not read the standard input")?
+        } else {
+            fs::read_to_string(filename)
+                .with_context(|| format!("could not read {}", filename.display()))?
+        };
+        contents.push(content.trim_end().to_string());
+    }
+    Ok(contents.join("\n\n") + "\n")
+}
+
+/// Opens the input files of a puzzle for [`Solution::parse_reader`]. A single file or the
+/// standard input is read while parsing, multiple files are joined by [`read_inputs`] first.
+pub fn open_inputs<P>(filenames: &[P]) -> anyhow::Result<Box<dyn BufRead>>
+where
+    P: AsRef<Path>,
+{
+    match filenames {
+        [filename] if filename.as_ref() == Path::new(STDIN) => {
+            Ok(Box::new(BufReader::new(io::stdin())))
+        }
+        [filename] => {
+            let filename = filename.as_ref();
+            let file = File::open(filename)
+                .with_context(|| format!("could not read {}", filename.display()))?;
+            Ok(Box::new(BufReader::new(file)))
+        }
+        _ => Ok(Box::new(Cursor::new(read_inputs(filenames)?))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{check_grid, cli::parse_param, Answer, Location, Part, Problem};
+
+    #[test]
+    fn test_part() {
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from(55312usize), Answer::Unsigned(55312));
+    }
+
+    #[test]
+    fn test_location() {
+        let input = "3   4\n4   x\n";
+        assert_eq!(Location::of(input, &input[10..]), Location::new(2, 5));
+        assert_eq!(Location::of(input, input), Location::new(1, 1));
+        assert_eq!(Location::new(2, 5).to_string(), "line 2, column 5");
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(
+            parse_param("blinks=75").unwrap(),
+            ("blinks".to_string(), "75".to_string())
+        );
+        assert!(parse_param("blinks").is_err());
+    }
+
+    #[test]
+    fn test_check_grid() {
+        assert_eq!(check_grid("ab\nba\n", char::is_alphabetic), []);
+        assert_eq!(
+            check_grid("", char::is_alphabetic),
+            [Problem::new("the grid is empty")]
+        );
+        let problems = check_grid("ab\nb1a\n", char::is_alphabetic);
+        assert_eq!(
+            problems
+                .iter()
+                .map(|problem| problem.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "line 2: row has 3 positions, the first row 2",
+                "line 2: invalid character '1' in column 2"
+            ]
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt::{self, Display};
+
+/// A position in the puzzle input, line and column are counted from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub const fn new(line: usize, column: usize) -> Self {
+        Location { line, column }
+    }
+
+    /// The location of `part` inside of `input`. `part` must be a subslice of `input`,
+    /// otherwise the location of the end of `input` is returned.
+    pub fn of(input: &str, part: &str) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
@@ -0,0 +1,63 @@
+use indicatif::{ProgressBar, ProgressStyle};
+use std::{
+    io::{self, IsTerminal},
+    sync::atomic::{AtomicBool, Ordering},
+};
+use tracing_subscriber::EnvFilter;
+
+static PROGRESS_BARS: AtomicBool = AtomicBool::new(false);
+
+/// Writes the tracing events to the standard error, only warnings by default and info, debug
+/// or trace events with a higher `verbosity`. `RUST_LOG` overrides the level, e.g.
+/// `RUST_LOG=day_06=debug`. Progress bars are only drawn with `progress_bars`.
+pub fn init(verbosity: u8, progress_bars: bool) {
+    let level = match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+    // keeps an already installed subscriber
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .try_init();
+    PROGRESS_BARS.store(progress_bars, Ordering::Relaxed);
+}
+
+/// The progress of a long search, drawn as a bar on the standard error if enabled by [`init`].
+/// The bar is removed when the progress is dropped.
+pub struct Progress {
+    bar: Option<ProgressBar>,
+}
+
+impl Progress {
+    pub fn new(length: u64, message: &'static str) -> Self {
+        let bar = PROGRESS_BARS.load(Ordering::Relaxed).then(|| {
+            let style = ProgressStyle::with_template(
+                "{msg} [{elapsed_precise}] {wide_bar} {human_pos}/{human_len} ({eta})",
+            )
+            .unwrap_or_else(|_| ProgressStyle::default_bar());
+            ProgressBar::new(length)
+                .with_style(style)
+                .with_message(message)
+        });
+        Progress { bar }
+    }
+
+    pub fn inc(&self, delta: u64) {
+        if let Some(bar) = &self.bar {
+            bar.inc(delta);
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if let Some(bar) = &self.bar {
+            bar.finish_and_clear();
+        }
+    }
+}
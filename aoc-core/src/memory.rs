@@ -0,0 +1,173 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::{self, Display},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+static IS_INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting the allocations and the allocated bytes. Installed by a
+/// binary with `#[global_allocator]`, [`measure`] then reports the allocations of a closure.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_allocation(size: usize) {
+        IS_INSTALLED.store(true, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn record_deallocation(size: usize) {
+        CURRENT_BYTES.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_deallocation(layout.size());
+    }
+
+    // a reallocation counts as a new allocation of the new size that frees the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_allocation(new_size);
+            Self::record_deallocation(layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// The allocations made while running a closure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// The number of allocations and reallocations.
+    pub allocations: usize,
+    /// The bytes of all allocations together, freed or not.
+    pub allocated_bytes: usize,
+    /// The highest number of bytes allocated at once, beyond what was allocated before.
+    pub peak_bytes: usize,
+}
+
+impl Display for AllocationStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, peak {}",
+            self.allocations,
+            Bytes(self.allocated_bytes),
+            Bytes(self.peak_bytes)
+        )
+    }
+}
+
+// a number of bytes with a binary unit, e.g. `1.5 MiB`
+struct Bytes(usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Whether the [`CountingAllocator`] is the global allocator of the running binary.
+pub fn is_counting() -> bool {
+    // the runtime allocates before `main`, so every installed allocator has counted already
+    IS_INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Runs `f` and returns its allocations, `None` if the [`CountingAllocator`] is not installed.
+/// The counters are global, the allocations of other threads running at the same time are
+/// included, so concurrent calls must be avoided for exact numbers.
+pub fn measure<T, F>(f: F) -> (T, Option<AllocationStats>)
+where
+    F: FnOnce() -> T,
+{
+    if !is_counting() {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let current_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(current_bytes, Ordering::Relaxed);
+    let result = f();
+    let stats = AllocationStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+        peak_bytes: PEAK_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(current_bytes),
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memory::{is_counting, measure, AllocationStats, CountingAllocator};
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        assert!(is_counting());
+        let (number_of_strings, stats) = measure(|| {
+            let mut numbers: Vec<u64> = Vec::with_capacity(1000);
+            numbers.extend(0..1000);
+            let strings: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+            strings.len()
+        });
+        let stats = stats.unwrap();
+        assert_eq!(number_of_strings, 1000);
+        // other tests may allocate at the same time
+        assert!(stats.allocations >= 1002, "{:?}", stats);
+        assert!(stats.allocated_bytes >= 8000 + 1000 * 24, "{:?}", stats);
+        assert!(stats.peak_bytes >= 8000 + 1000 * 24, "{:?}", stats);
+    }
+
+    #[test]
+    fn test_display() {
+        let stats = AllocationStats {
+            allocations: 3,
+            allocated_bytes: 3 * 1024 * 1024 / 2,
+            peak_bytes: 100,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "3 allocations, 1.5 MiB allocated, peak 100 B"
+        );
+    }
+}
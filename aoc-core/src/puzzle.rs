@@ -0,0 +1,81 @@
+use crate::{Answer, Parameters, Part, Problem, Solution};
+use anyhow::Context;
+use std::{any::Any, io::Read};
+
+/// Object safe view of a [`Solution`], so tools can handle all days alike.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    fn input_files(&self) -> &'static [&'static str];
+    fn parse(&self, input: &str) -> anyhow::Result<Box<dyn Any>>;
+    /// [`Puzzle::parse`] of an input read by [`Solution::parse_reader`].
+    fn parse_reader(&self, reader: &mut dyn Read) -> anyhow::Result<Box<dyn Any>>;
+    fn generate(&self, seed: u64, size: usize) -> String;
+    /// All problems of the input, the error of [`Puzzle::parse`] if the checks of the day
+    /// missed it.
+    fn validate(&self, input: &str) -> Vec<Problem>;
+    /// Solves a part on an input returned by [`Puzzle::parse`], with `name=value` parameters.
+    fn solve(
+        &self,
+        input: &dyn Any,
+        part: Part,
+        params: &[(String, String)],
+    ) -> anyhow::Result<Answer>;
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input_files(&self) -> &'static [&'static str] {
+        S::INPUT_FILES
+    }
+
+    fn parse(&self, input: &str) -> anyhow::Result<Box<dyn Any>> {
+        let _span = tracing::info_span!("parse", day = S::DAY).entered();
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn parse_reader(&self, reader: &mut dyn Read) -> anyhow::Result<Box<dyn Any>> {
+        let _span = tracing::info_span!("parse", day = S::DAY).entered();
+        Ok(Box::new(S::parse_reader(reader)?))
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        S::generate(seed, size)
+    }
+
+    fn validate(&self, input: &str) -> Vec<Problem> {
+        let mut problems = S::validate(input);
+        if problems.is_empty() {
+            if let Err(err) = S::parse(input) {
+                problems.push(Problem::new(format!("{:#}", err)));
+            }
+        }
+        problems.sort_by_key(|problem| problem.line);
+        problems
+    }
+
+    fn solve(
+        &self,
+        input: &dyn Any,
+        part: Part,
+        params: &[(String, String)],
+    ) -> anyhow::Result<Answer> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .context("input was not parsed by this puzzle")?;
+        let mut parameters = S::Params::default();
+        for (name, value) in params {
+            parameters.set(name, value)?;
+        }
+        let _span = tracing::info_span!("solve", day = S::DAY, %part).entered();
+        let answer = S::solve(input, part, &parameters)?;
+        tracing::info!(%answer, "solved");
+        Ok(answer)
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt::{self, Display};
+
+/// A violation of the input format or of an invariant of the puzzle, found without solving it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// The 1-based line of the input, `None` if the problem concerns the whole input.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Problem {
+    pub fn new<M>(message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Problem {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line<M>(line: usize, message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Problem {
+            line: Some(line),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Checks that the input is a rectangular grid of characters accepted by `is_valid`, one row
+/// per line. Reports every row of a different width and every invalid character.
+pub fn check_grid<F>(input: &str, is_valid: F) -> Vec<Problem>
+where
+    F: Fn(char) -> bool,
+{
+    let mut problems = Vec::new();
+    let mut width = None;
+    for (index, line) in input.lines().enumerate() {
+        let length = line.chars().count();
+        match width {
+            None => width = Some(length),
+            Some(width) if width != length => problems.push(Problem::at_line(
+                index + 1,
+                format!("row has {} positions, the first row {}", length, width),
+            )),
+            Some(_) => {}
+        }
+        for (column, char) in line.chars().enumerate() {
+            if !is_valid(char) {
+                problems.push(Problem::at_line(
+                    index + 1,
+                    format!("invalid character {:?} in column {}", char, column + 1),
+                ));
+            }
+        }
+    }
+    if width.is_none_or(|width| width == 0) {
+        problems.push(Problem::new("the grid is empty"));
+    }
+    problems
+}
+
+/// Checks that every whitespace separated token of the line is a number of type `T`, e.g. `u64`.
+pub fn check_numbers<T>(line_number: usize, line: &str, what: &str) -> Vec<Problem>
+where
+    T: std::str::FromStr,
+{
+    line.split_whitespace()
+        .filter(|token| token.parse::<T>().is_err())
+        .map(|token| Problem::at_line(line_number, format!("invalid {} {:?}", what, token)))
+        .collect()
+}
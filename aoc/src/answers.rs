@@ -0,0 +1,183 @@
+use crate::days;
+use anyhow::Context;
+use aoc_core::{Answer, Part};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    num::ParseIntError,
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// The file in every day crate that records the known answers.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The known answers of a day, one entry per input.
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    pub entries: Vec<KnownAnswer>,
+}
+
+/// The answers of both parts for one input, paths are relative to the day crate.
+#[derive(Debug, Deserialize)]
+pub struct KnownAnswer {
+    pub input: Vec<PathBuf>,
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    pub part1: Option<ExpectedAnswer>,
+    pub part2: Option<ExpectedAnswer>,
+}
+
+/// A recorded answer. TOML integers are 64-bit signed, so larger unsigned answers are
+/// recorded as strings, e.g. `part2 = "18446744073709551615"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "RecordedValue")]
+pub struct ExpectedAnswer(i128);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RecordedValue {
+    Integer(i64),
+    Text(String),
+}
+
+impl TryFrom<RecordedValue> for ExpectedAnswer {
+    type Error = ParseIntError;
+
+    fn try_from(value: RecordedValue) -> Result<Self, Self::Error> {
+        match value {
+            RecordedValue::Integer(value) => Ok(ExpectedAnswer(value.into())),
+            RecordedValue::Text(text) => Ok(ExpectedAnswer(text.parse()?)),
+        }
+    }
+}
+
+impl Display for ExpectedAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl KnownAnswer {
+    pub fn expected(&self, part: Part) -> Option<ExpectedAnswer> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+
+    pub fn matches(&self, part: Part, answer: &Answer) -> bool {
+        let value = match answer {
+            Answer::Signed(value) => i128::from(*value),
+            Answer::Unsigned(value) => match i128::try_from(*value) {
+                Ok(value) => value,
+                Err(_) => return false,
+            },
+            // the recorded answers are integers
+            Answer::Decimal(_) => return false,
+        };
+        self.expected(part) == Some(ExpectedAnswer(value))
+    }
+
+    pub fn params(&self) -> Vec<(String, String)> {
+        self.params
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect()
+    }
+}
+
+impl Answers {
+    /// Reads the answers file of a day, no answers if the day has none.
+    pub fn load(day: u8) -> anyhow::Result<Self> {
+        let path = days::crate_dir(day).join(ANSWERS_FILE);
+        match fs::read_to_string(&path) {
+            Ok(content) => content
+                .parse()
+                .with_context(|| format!("could not parse {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err).with_context(|| format!("could not read {}", path.display())),
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Answers;
+    use aoc_core::{Answer, Part};
+
+    #[test]
+    fn test_parse() {
+        let answers: Answers = r#"
+            [[answer]]
+            input = ["data/test_data_1"]
+            params = { blinks = "25" }
+            part1 = 55312
+
+            [[answer]]
+            input = ["data/data_1"]
+            part2 = 229557103025807
+        "#
+        .parse()
+        .unwrap();
+        assert_eq!(answers.entries.len(), 2);
+        let test = &answers.entries[0];
+        assert_eq!(test.params(), [("blinks".to_string(), "25".to_string())]);
+        assert!(test.matches(Part::One, &Answer::Unsigned(55312)));
+        assert!(!test.matches(Part::Two, &Answer::Unsigned(55312)));
+        assert!(answers.entries[1].matches(Part::Two, &Answer::Signed(229557103025807)));
+        assert!(answers.entries[1].matches(Part::Two, &Answer::Unsigned(229557103025807)));
+    }
+
+    #[test]
+    fn test_parse_large_answers() {
+        let answers: Answers = r#"
+            [[answer]]
+            input = ["data/data_1"]
+            part1 = -3
+            part2 = "18446744073709551615"
+        "#
+        .parse()
+        .unwrap();
+        let answer = &answers.entries[0];
+        assert!(answer.matches(Part::One, &Answer::Signed(-3)));
+        assert!(answer.matches(Part::Two, &Answer::from(u64::MAX)));
+        assert!(!answer.matches(Part::Two, &Answer::Signed(-1)));
+        assert!(!answer.matches(Part::Two, &Answer::Unsigned(u128::MAX)));
+        assert!(!answer.matches(Part::Two, &Answer::Decimal(0.5)));
+        assert_eq!(
+            answer.expected(Part::Two).unwrap().to_string(),
+            "18446744073709551615"
+        );
+        assert!("[[answer]]\ninput = []\npart1 = \"many\"\n"
+            .parse::<Answers>()
+            .is_err());
+    }
+
+    // a day may have no answers yet, e.g. right after `aoc new`
+    #[test]
+    fn test_recorded_answers() {
+        for puzzle in crate::days::PUZZLES {
+            let answers = Answers::load(puzzle.day()).unwrap();
+            for answer in answers.entries {
+                assert!(!answer.input.is_empty(), "day {}", puzzle.day());
+                assert!(
+                    answer.part1.is_some() || answer.part2.is_some(),
+                    "day {}",
+                    puzzle.day()
+                );
+            }
+        }
+    }
+}
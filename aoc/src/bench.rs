@@ -0,0 +1,196 @@
+use crate::output::nanos;
+use anyhow::Context;
+use aoc_core::{NotSolved, Part, Puzzle};
+use serde::Serialize;
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// The timed step of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Stage {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Stage::Part1,
+            Part::Two => Stage::Part2,
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Minimum, median and mean of the measured durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// `None` without any durations.
+    pub fn from_durations(durations: &[Duration]) -> Option<Self> {
+        let mut sorted = durations.to_vec();
+        sorted.sort_unstable();
+        let count = u32::try_from(sorted.len())
+            .ok()
+            .filter(|count| 0 < *count)?;
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / count,
+        })
+    }
+}
+
+/// One benchmarked stage of a day, durations in nanoseconds.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchResult {
+    pub day: u8,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl BenchResult {
+    fn new(day: u8, stage: Stage, iterations: usize, stats: Stats) -> Self {
+        BenchResult {
+            day,
+            stage,
+            iterations,
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            mean_ns: nanos(stats.mean),
+        }
+    }
+}
+
+impl Display for BenchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02} - {:<6}  min {:>12.3?}  median {:>12.3?}  mean {:>12.3?}  ({} iterations)",
+            self.day,
+            self.stage.to_string(),
+            Duration::from_nanos(self.min_ns),
+            Duration::from_nanos(self.median_ns),
+            Duration::from_nanos(self.mean_ns),
+            self.iterations
+        )
+    }
+}
+
+/// The number of untimed warmup runs and timed iterations.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+fn measure<T, F>(options: BenchOptions, mut f: F) -> anyhow::Result<Stats>
+where
+    F: FnMut() -> anyhow::Result<T>,
+{
+    for _ in 0..options.warmup {
+        black_box(f()?);
+    }
+    let mut durations = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations {
+        let start = Instant::now();
+        black_box(f()?);
+        durations.push(start.elapsed());
+    }
+    Stats::from_durations(&durations).context("at least one iteration is needed")
+}
+
+/// Times parsing the input and solving the parts separately, the input is read beforehand.
+pub fn bench_day(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    params: &[(String, String)],
+    options: BenchOptions,
+) -> anyhow::Result<Vec<BenchResult>> {
+    let day = puzzle.day();
+    let mut results = Vec::new();
+
+    let stats = measure(options, || puzzle.parse(input))?;
+    results.push(BenchResult::new(
+        day,
+        Stage::Parse,
+        options.iterations,
+        stats,
+    ));
+
+    let parsed_input = puzzle.parse(input)?;
+    for part in parts {
+        let stats = match measure(options, || {
+            puzzle.solve(parsed_input.as_ref(), *part, params)
+        }) {
+            Err(err) if err.is::<NotSolved>() => {
+                tracing::warn!("{}, not benchmarked", err);
+                continue;
+            }
+            stats => stats?,
+        };
+        results.push(BenchResult::new(
+            day,
+            Stage::from(*part),
+            options.iterations,
+            stats,
+        ));
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::Stats;
+    use std::time::Duration;
+
+    #[test]
+    fn test_stats() {
+        let millis = |values: &[u64]| -> Vec<Duration> {
+            values
+                .iter()
+                .map(|value| Duration::from_millis(*value))
+                .collect()
+        };
+
+        let stats = Stats::from_durations(&millis(&[5, 1, 3])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+
+        let stats = Stats::from_durations(&millis(&[4, 1, 2, 9])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+
+        assert_eq!(Stats::from_durations(&[]), None);
+    }
+}
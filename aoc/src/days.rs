@@ -0,0 +1,80 @@
+use anyhow::Context;
+use aoc_core::Puzzle;
+use std::path::{Path, PathBuf};
+
+pub const PUZZLES: &[&dyn Puzzle] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+];
+
+/// The days of [`PUZZLES`] in ascending order, there may be days missing in between.
+pub fn days() -> Vec<u8> {
+    let mut days: Vec<u8> = PUZZLES.iter().map(|puzzle| puzzle.day()).collect();
+    days.sort_unstable();
+    days
+}
+
+pub fn find(day: u8) -> anyhow::Result<&'static dyn Puzzle> {
+    PUZZLES
+        .iter()
+        .find(|puzzle| puzzle.day() == day)
+        .copied()
+        .with_context(|| format!("there is no puzzle for day {}", day))
+}
+
+pub fn crate_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day-{:02}", day))
+}
+
+// the data files shipped with the day crate, or below `AOC_INPUT_DIR` if set
+pub fn default_inputs(puzzle: &dyn Puzzle) -> Vec<PathBuf> {
+    aoc_core::default_inputs(puzzle, crate_dir(puzzle.day()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::PUZZLES;
+    use aoc_core::{NotSolved, Part};
+
+    // every generated input is deterministic, passes the validator and is solvable
+    #[test]
+    fn test_generated_inputs() {
+        for puzzle in PUZZLES {
+            let day = puzzle.day();
+            for seed in 0..5 {
+                let input = puzzle.generate(seed, 20);
+                assert_eq!(input, puzzle.generate(seed, 20), "day {}", day);
+                assert_ne!(input, puzzle.generate(seed + 100, 20), "day {}", day);
+                assert_eq!(
+                    puzzle.validate(&input),
+                    [],
+                    "day {} seed {}:\n{}",
+                    day,
+                    seed,
+                    input
+                );
+                let parsed_input = puzzle.parse(&input).unwrap();
+                for part in Part::ALL {
+                    match puzzle.solve(parsed_input.as_ref(), part, &[]) {
+                        Err(err) if err.is::<NotSolved>() => {}
+                        result => {
+                            result.unwrap();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
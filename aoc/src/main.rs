@@ -0,0 +1,532 @@
+mod answers;
+mod bench;
+mod days;
+mod output;
+mod scaffold;
+mod watch;
+
+use answers::Answers;
+use anyhow::{bail, Context};
+use aoc_core::{
+    cli::parse_param,
+    memory::{self, AllocationStats},
+    open_inputs, read_inputs, NotSolved, Part, Puzzle,
+};
+use bench::BenchOptions;
+use clap::{ArgAction, Parser, Subcommand};
+use output::Format;
+#[cfg(all(feature = "parallel", not(feature = "count-allocations")))]
+use rayon::prelude::*;
+use serde::Serialize;
+use std::{
+    ffi::OsString,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::Instant,
+};
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 puzzles")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log more details to the standard error, repeat for debug and trace output
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Show progress bars for long searches
+    #[arg(long, global = true)]
+    progress: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the puzzles of one day, a range of days or all days
+    Run {
+        /// Day (`7`) or inclusive range of days (`3..7` or `3-7`), all days if omitted
+        #[arg(short, long)]
+        day: Option<DaySelection>,
+        /// Puzzle part (`1` or `2`), both parts if omitted
+        #[arg(short, long)]
+        part: Option<Part>,
+        /// Input file(s), `-` reads the standard input, the data files of the day crate if omitted
+        #[arg(short, long, requires = "day")]
+        input: Vec<PathBuf>,
+        /// Day specific parameter, e.g. `blinks=25` for day 11
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+        /// Output format of the answers
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Time parsing and both parts of the puzzles over several iterations
+    Bench {
+        /// Day (`7`) or inclusive range of days (`3..7` or `3-7`), all days if omitted
+        #[arg(short, long)]
+        day: Option<DaySelection>,
+        /// Puzzle part (`1` or `2`), both parts if omitted
+        #[arg(short, long)]
+        part: Option<Part>,
+        /// Input file(s), `-` reads the standard input, the data files of the day crate if omitted
+        #[arg(short, long, requires = "day")]
+        input: Vec<PathBuf>,
+        /// Day specific parameter, e.g. `blinks=25` for day 11
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+        /// Number of timed iterations of every step
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Number of untimed runs before the timed iterations
+        #[arg(short, long, default_value_t = 1)]
+        warmup: u32,
+        /// Output format of the timings
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Check the inputs for format problems without solving the puzzles
+    Validate {
+        /// Day (`7`) or inclusive range of days (`3..7` or `3-7`), all days if omitted
+        #[arg(short, long)]
+        day: Option<DaySelection>,
+        /// Input file(s), `-` reads the standard input, the data files of the day crate if omitted
+        #[arg(short, long, requires = "day")]
+        input: Vec<PathBuf>,
+    },
+    /// Print a random input of a day, e.g. to pipe into `run --input -`
+    Generate {
+        /// Day of the puzzle
+        #[arg(short, long)]
+        day: u8,
+        /// Seed of the random generator, the same seed gives the same input
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// Size of the input, e.g. the number of lines or grid rows
+        #[arg(short = 'n', long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Create the crate of a new day from the template and register it in the workspace
+    New {
+        /// Day of the new puzzle
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Re-run the tests and the puzzles of a day whenever its sources or data files change
+    Watch {
+        /// Day of the puzzle
+        #[arg(short, long)]
+        day: u8,
+        /// Puzzle part (`1` or `2`), both parts if omitted
+        #[arg(short, long)]
+        part: Option<Part>,
+        /// Input file(s), the data files of the day crate if omitted
+        #[arg(short, long)]
+        input: Vec<PathBuf>,
+        /// Day specific parameter, e.g. `blinks=25` for day 11
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+    },
+    /// Compare the answers of the recorded inputs with the `answers.toml` of each day
+    Verify {
+        /// Day (`7`) or inclusive range of days (`3..7` or `3-7`), all days if omitted
+        #[arg(short, long)]
+        day: Option<DaySelection>,
+    },
+}
+
+#[derive(Clone)]
+struct DaySelection(RangeInclusive<u8>);
+
+impl FromStr for DaySelection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, last) = match s.split_once("..").or_else(|| s.split_once('-')) {
+            Some((first, last)) => (first.parse()?, last.parse()?),
+            None => {
+                let day = s.parse()?;
+                (day, day)
+            }
+        };
+        if first == last {
+            days::find(first)?;
+        } else if last < first || DaySelection(first..=last).days().is_empty() {
+            bail!("there are no days between {} and {}", first, last);
+        }
+        Ok(DaySelection(first..=last))
+    }
+}
+
+impl DaySelection {
+    // the days of the runner in the selection
+    fn days(&self) -> Vec<u8> {
+        days::days()
+            .into_iter()
+            .filter(|day| self.0.contains(day))
+            .collect()
+    }
+}
+
+// the selected days, input files can only be given for a single day
+fn selected_days(day: Option<DaySelection>, input: &[PathBuf]) -> anyhow::Result<Vec<u8>> {
+    let days = day.map_or_else(days::days, |selection| selection.days());
+    if !input.is_empty() && days.len() != 1 {
+        bail!("an input file can only be given for a single day");
+    }
+    Ok(days)
+}
+
+// the given input files of a day, its data files if there are none
+fn day_inputs(puzzle: &dyn Puzzle, input: &[PathBuf]) -> Vec<PathBuf> {
+    if input.is_empty() {
+        days::default_inputs(puzzle)
+    } else {
+        input.to_vec()
+    }
+}
+
+fn read_day_input(puzzle: &dyn Puzzle, input: &[PathBuf]) -> anyhow::Result<String> {
+    read_inputs(&day_inputs(puzzle, input))
+}
+
+// the input files as shown in the results, the data files relative to the workspace
+fn input_name(puzzle: &dyn Puzzle, input: &[PathBuf]) -> String {
+    if input.is_empty() {
+        puzzle
+            .input_files()
+            .iter()
+            .map(|file| format!("day-{:02}/{}", puzzle.day(), file))
+            .collect::<Vec<_>>()
+            .join(", ")
+    } else {
+        input
+            .iter()
+            .map(|file| file.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// The answer of one part, elapsed times in nanoseconds, parsing includes reading the input.
+/// There is no answer if the part is not solved yet. The allocations of solving the part are
+/// only counted with the `count-allocations` feature.
+#[derive(Serialize)]
+struct RunResult {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: Option<String>,
+    parse_ns: u64,
+    elapsed_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocations: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocated_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peak_bytes: Option<usize>,
+}
+
+impl RunResult {
+    fn allocation_stats(&self) -> Option<AllocationStats> {
+        Some(AllocationStats {
+            allocations: self.allocations?,
+            allocated_bytes: self.allocated_bytes?,
+            peak_bytes: self.peak_bytes?,
+        })
+    }
+}
+
+// reports the result of every day in the order of the days, stopping at the first error. With
+// the `parallel` feature all days are processed concurrently before the first report, unless
+// allocations are counted: the counters are global and would mix the allocations of the days.
+fn for_each_day<T, F, R>(days: Vec<u8>, process_day: F, mut report: R) -> anyhow::Result<()>
+where
+    T: Send,
+    F: Fn(u8) -> T + Sync + Send,
+    R: FnMut(T) -> anyhow::Result<()>,
+{
+    #[cfg(all(feature = "parallel", not(feature = "count-allocations")))]
+    let day_results: Vec<T> = days.into_par_iter().map(process_day).collect();
+    #[cfg(any(not(feature = "parallel"), feature = "count-allocations"))]
+    let day_results = days.into_iter().map(process_day);
+    for day_result in day_results {
+        report(day_result)?;
+    }
+    Ok(())
+}
+
+fn run_day(
+    day: u8,
+    parts: &[Part],
+    input: &[PathBuf],
+    params: &[(String, String)],
+) -> anyhow::Result<Vec<RunResult>> {
+    let puzzle = days::find(day)?;
+    let mut day_input = open_inputs(&day_inputs(puzzle, input))?;
+    // the input is read while it is parsed, a single file line by line if the day supports it
+    let start = Instant::now();
+    let parsed_input = puzzle
+        .parse_reader(&mut day_input)
+        .with_context(|| format!("could not parse the input of day {}", day))?;
+    let parse_elapsed = start.elapsed();
+    let mut results = Vec::new();
+    for part in parts {
+        let start = Instant::now();
+        let (solution, allocation_stats) =
+            memory::measure(|| puzzle.solve(parsed_input.as_ref(), *part, params));
+        let elapsed = start.elapsed();
+        let answer = match solution {
+            Ok(answer) => Some(answer.to_string()),
+            Err(err) if err.is::<NotSolved>() => None,
+            Err(err) => return Err(err.context(format!("day {} part {} failed", day, part))),
+        };
+        results.push(RunResult {
+            day,
+            part: match part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            input: input_name(puzzle, input),
+            answer,
+            parse_ns: output::nanos(parse_elapsed),
+            elapsed_ns: output::nanos(elapsed),
+            allocations: allocation_stats.map(|stats| stats.allocations),
+            allocated_bytes: allocation_stats.map(|stats| stats.allocated_bytes),
+            peak_bytes: allocation_stats.map(|stats| stats.peak_bytes),
+        });
+    }
+    Ok(results)
+}
+
+fn run(
+    day: Option<DaySelection>,
+    part: Option<Part>,
+    input: Vec<PathBuf>,
+    params: Vec<(String, String)>,
+    format: Format,
+) -> anyhow::Result<()> {
+    let days = selected_days(day, &input)?;
+    let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+
+    let mut results = Vec::new();
+    for_each_day(
+        days,
+        |day| run_day(day, &parts, &input, &params),
+        |day_results| {
+            let day_results = day_results?;
+            if format == Format::Text {
+                for result in &day_results {
+                    let answer = result.answer.as_deref().unwrap_or("not solved yet");
+                    match result.allocation_stats() {
+                        Some(stats) => println!(
+                            "Day {:02} - Solution {}: {} ({})",
+                            result.day, result.part, answer, stats
+                        ),
+                        None => println!(
+                            "Day {:02} - Solution {}: {}",
+                            result.day, result.part, answer
+                        ),
+                    }
+                }
+            }
+            results.extend(day_results);
+            Ok(())
+        },
+    )?;
+    output::print_records(&results, format)
+}
+
+fn bench(
+    day: Option<DaySelection>,
+    part: Option<Part>,
+    input: Vec<PathBuf>,
+    params: Vec<(String, String)>,
+    options: BenchOptions,
+    format: Format,
+) -> anyhow::Result<()> {
+    let days = selected_days(day, &input)?;
+    let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+
+    let mut results = Vec::new();
+    for day in days {
+        let puzzle = days::find(day)?;
+        let day_input = read_day_input(puzzle, &input)?;
+        let day_results = bench::bench_day(puzzle, &day_input, &parts, &params, options)
+            .with_context(|| format!("could not benchmark day {}", day))?;
+        if format == Format::Text {
+            for result in &day_results {
+                println!("{}", result);
+            }
+        }
+        results.extend(day_results);
+    }
+    output::print_records(&results, format)
+}
+
+// returns whether all inputs are free of problems
+fn validate(day: Option<DaySelection>, input: Vec<PathBuf>) -> anyhow::Result<bool> {
+    let days = selected_days(day, &input)?;
+    let mut invalid_inputs = 0;
+    for day in days {
+        let puzzle = days::find(day)?;
+        let label = format!("Day {:02} - {}", day, input_name(puzzle, &input));
+        let problems = puzzle.validate(&read_day_input(puzzle, &input)?);
+        if problems.is_empty() {
+            println!("{}: ok", label);
+        } else {
+            invalid_inputs += 1;
+            for problem in problems {
+                println!("{}: {}", label, problem);
+            }
+        }
+    }
+    Ok(invalid_inputs == 0)
+}
+
+// a line per recorded answer of the day and whether it was reproduced
+fn verify_day(day: u8) -> anyhow::Result<Vec<(String, bool)>> {
+    let puzzle = days::find(day)?;
+    let crate_dir = days::crate_dir(day);
+    let mut checks = Vec::new();
+    for known_answer in Answers::load(day)?.entries {
+        let inputs: Vec<_> = known_answer
+            .input
+            .iter()
+            .map(|file| crate_dir.join(file))
+            .collect();
+        let input_names = known_answer
+            .input
+            .iter()
+            .map(|file| file.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let parsed_input = puzzle
+            .parse_reader(&mut open_inputs(&inputs)?)
+            .with_context(|| format!("could not parse {} of day {}", input_names, day))?;
+        for part in Part::ALL {
+            let Some(expected) = known_answer.expected(part) else {
+                continue;
+            };
+            let label = format!("Day {:02} - {} - Solution {}", day, input_names, part);
+            checks.push(
+                match puzzle.solve(parsed_input.as_ref(), part, &known_answer.params()) {
+                    Ok(answer) if known_answer.matches(part, &answer) => {
+                        (format!("{}: {} ok", label, answer), true)
+                    }
+                    Ok(answer) => (
+                        format!("{}: {} MISMATCH, expected {}", label, answer, expected),
+                        false,
+                    ),
+                    Err(err) => (
+                        format!("{}: FAILED, expected {}: {:#}", label, expected, err),
+                        false,
+                    ),
+                },
+            );
+        }
+    }
+    Ok(checks)
+}
+
+// returns whether all recorded answers were reproduced
+fn verify(day: Option<DaySelection>) -> anyhow::Result<bool> {
+    let days = selected_days(day, &[])?;
+    let mut checked = 0;
+    let mut failures = 0;
+
+    for_each_day(days, verify_day, |checks| {
+        for (line, is_reproduced) in checks? {
+            checked += 1;
+            if !is_reproduced {
+                failures += 1;
+            }
+            println!("{}", line);
+        }
+        Ok(())
+    })?;
+
+    println!("{} answers checked, {} failed", checked, failures);
+    Ok(failures == 0)
+}
+
+fn new_day(day: u8) -> anyhow::Result<()> {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for file in scaffold::new_day(&workspace, day)? {
+        println!("created {}", file.display());
+    }
+    println!(
+        "registered day-{:02} in the workspace, add the example to day-{:02}/data/test_data_1",
+        day, day
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_core::logging::init(cli.verbose, cli.progress);
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            params,
+            format,
+        } => run(day, part, input, params, format).map(|()| true),
+        Command::Bench {
+            day,
+            part,
+            input,
+            params,
+            iterations,
+            warmup,
+            format,
+        } => {
+            let options = BenchOptions {
+                warmup: warmup as usize,
+                iterations: iterations as usize,
+            };
+            bench(day, part, input, params, options, format).map(|()| true)
+        }
+        Command::Validate { day, input } => validate(day, input),
+        Command::Generate { day, seed, size } => days::find(day).map(|puzzle| {
+            print!("{}", puzzle.generate(seed, size));
+            true
+        }),
+        Command::New { day } => new_day(day).map(|()| true),
+        Command::Watch {
+            day,
+            part,
+            input,
+            params,
+        } => days::find(day).and_then(|_| {
+            let mut day_args: Vec<OsString> = Vec::new();
+            if let Some(part) = part {
+                day_args.extend(["--part".into(), part.to_string().into()]);
+            }
+            for file in &input {
+                day_args.extend(["--input".into(), file.into()]);
+            }
+            for (name, value) in &params {
+                day_args.extend(["--param".into(), format!("{}={}", name, value).into()]);
+            }
+            day_args.extend((0..cli.verbose).map(|_| "--verbose".into()));
+            if cli.progress {
+                day_args.push("--progress".into());
+            }
+            watch::watch(day, &input, &day_args).map(|()| true)
+        }),
+        Command::Verify { day } => verify(day),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Error: {:?}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
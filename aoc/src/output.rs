@@ -0,0 +1,83 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+/// How the results of a command are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable lines
+    #[default]
+    Text,
+    /// A JSON array with one object per record
+    Json,
+    /// CSV with a header line
+    Csv,
+}
+
+/// Writes the records as JSON or CSV, `Format::Text` is printed by each command itself.
+pub fn write_records<T, W>(records: &[T], format: Format, writer: W) -> anyhow::Result<()>
+where
+    T: Serialize,
+    W: Write,
+{
+    match format {
+        Format::Text => {}
+        Format::Json => {
+            let mut writer = writer;
+            serde_json::to_writer_pretty(&mut writer, records)?;
+            writeln!(writer)?;
+        }
+        Format::Csv => {
+            let mut csv_writer = csv::Writer::from_writer(writer);
+            for record in records {
+                csv_writer.serialize(record)?;
+            }
+            csv_writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Durations are written as whole nanoseconds.
+pub fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+pub fn print_records<T>(records: &[T], format: Format) -> anyhow::Result<()>
+where
+    T: Serialize,
+{
+    write_records(records, format, io::stdout().lock())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::output::{write_records, Format};
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Record {
+        day: u8,
+        answer: String,
+    }
+
+    #[test]
+    fn test_write_records() {
+        let records = [Record {
+            day: 7,
+            answer: "3749".to_string(),
+        }];
+
+        let mut csv = Vec::new();
+        write_records(&records, Format::Csv, &mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "day,answer\n7,3749\n");
+
+        let mut json = Vec::new();
+        write_records(&records, Format::Json, &mut json).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(value[0]["answer"], "3749");
+    }
+}
@@ -0,0 +1,154 @@
+use anyhow::{bail, Context};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The files of a new day crate, relative to the crate directory, and their templates.
+const TEMPLATES: &[(&str, &str)] = &[
+    (".gitignore", include_str!("../templates/gitignore.tmpl")),
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    (
+        "answers.toml",
+        include_str!("../templates/answers.toml.tmpl"),
+    ),
+    (
+        "benches/puzzle.rs",
+        include_str!("../templates/puzzle.rs.tmpl"),
+    ),
+    ("data/data_1", ""),
+    ("data/test_data_1", ""),
+    (
+        "src/generator.rs",
+        include_str!("../templates/generator.rs.tmpl"),
+    ),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+];
+
+// `{{NN}}` is the zero padded day as in the crate name, `{{N}}` the plain number
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{N}}", &day.to_string())
+}
+
+/// Creates the crate `day-NN` in the workspace and registers it in the workspace, the
+/// dependencies of the runner and its list of puzzles. Returns the created files relative to the
+/// workspace.
+pub fn new_day(workspace: &Path, day: u8) -> anyhow::Result<Vec<PathBuf>> {
+    let crate_name = format!("day-{:02}", day);
+    let crate_dir = workspace.join(&crate_name);
+    if crate_dir.exists() {
+        bail!("the crate {} already exists", crate_name);
+    }
+
+    let workspace_manifest = workspace.join("Cargo.toml");
+    let runner_manifest = workspace.join("aoc").join("Cargo.toml");
+    let runner_days = workspace.join("aoc").join("src").join("days.rs");
+    // update all registrations in memory first, nothing is written if one of them fails
+    let registrations = [
+        (
+            &workspace_manifest,
+            add_member(&read(&workspace_manifest)?, day)?,
+        ),
+        (
+            &runner_manifest,
+            add_dependency(&read(&runner_manifest)?, day)?,
+        ),
+        (&runner_days, add_puzzle(&read(&runner_days)?, day)?),
+    ];
+
+    let mut created_files = Vec::new();
+    for (file, template) in TEMPLATES {
+        let path = crate_dir.join(file);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("could not create {}", dir.display()))?;
+        }
+        fs::write(&path, render(template, day))
+            .with_context(|| format!("could not write {}", path.display()))?;
+        created_files.push(Path::new(&crate_name).join(file));
+    }
+    for (path, content) in registrations {
+        fs::write(path, content).with_context(|| format!("could not write {}", path.display()))?;
+    }
+    Ok(created_files)
+}
+
+fn read(path: &Path) -> anyhow::Result<String> {
+    fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))
+}
+
+// inserts a line into the sorted lines between the first line starting with `start` and the
+// next line starting with `end` or the end of the content
+fn insert_sorted(content: &str, start: &str, end: &str, line: String) -> anyhow::Result<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let first = lines
+        .iter()
+        .position(|l| l.starts_with(start))
+        .with_context(|| format!("no line starting with `{}`", start))?
+        + 1;
+    let last = lines[first..]
+        .iter()
+        .position(|l| l.starts_with(end))
+        .map_or(lines.len(), |index| first + index);
+    if lines[first..last].contains(&line.as_str()) {
+        bail!("`{}` is already registered", line.trim());
+    }
+    let index = first + lines[first..last].partition_point(|l| *l < line.as_str());
+    lines.insert(index, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_member(workspace_manifest: &str, day: u8) -> anyhow::Result<String> {
+    let member = format!("    \"day-{:02}\",", day);
+    insert_sorted(workspace_manifest, "members = [", "]", member)
+}
+
+fn add_dependency(runner_manifest: &str, day: u8) -> anyhow::Result<String> {
+    let dependency = format!("day-{0:02} = {{ path = \"../day-{0:02}\" }}", day);
+    insert_sorted(runner_manifest, "[dependencies]", "[", dependency)
+}
+
+fn add_puzzle(runner_days: &str, day: u8) -> anyhow::Result<String> {
+    let puzzle = format!("    &day_{0:02}::Day{0:02},", day);
+    insert_sorted(runner_days, "pub const PUZZLES", "];", puzzle)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{add_dependency, add_member, add_puzzle, render};
+
+    #[test]
+    fn test_render() {
+        let main = render(include_str!("../templates/main.rs.tmpl"), 7);
+        assert!(main.contains("use day_07::Day07;"));
+        let lib = render(include_str!("../templates/lib.rs.tmpl"), 7);
+        assert!(lib.contains("const DAY: u8 = 7;"));
+        assert!(!lib.contains("{{"));
+    }
+
+    #[test]
+    fn test_register() {
+        let workspace_manifest =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-12\",\n    \"grid\",\n]\n";
+        assert_eq!(
+            add_member(workspace_manifest, 13).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-12\",\n    \"day-13\",\n    \"grid\",\n]\n"
+        );
+        assert!(add_member(workspace_manifest, 12).is_err());
+
+        let runner_manifest = "[dependencies]\nday-12 = { path = \"../day-12\" }\nserde = \"1\"\n";
+        assert_eq!(
+            add_dependency(runner_manifest, 13).unwrap(),
+            "[dependencies]\nday-12 = { path = \"../day-12\" }\nday-13 = { path = \"../day-13\" }\nserde = \"1\"\n"
+        );
+
+        let runner_days = "pub const PUZZLES: &[&dyn Puzzle] = &[\n    &day_12::Day12,\n];\n";
+        assert_eq!(
+            add_puzzle(runner_days, 13).unwrap(),
+            "pub const PUZZLES: &[&dyn Puzzle] = &[\n    &day_12::Day12,\n    &day_13::Day13,\n];\n"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::days;
+use anyhow::{bail, Context};
+use notify::{Event, RecursiveMode, Watcher};
+use std::{
+    collections::BTreeMap,
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+/// How long the files have to be unchanged before a run, an editor may write several events.
+const QUIET_PERIOD: Duration = Duration::from_millis(300);
+
+/// The answers printed by a day binary, by part.
+pub type Answers = BTreeMap<String, String>;
+
+/// Runs the tests and the binary of a day whenever its `src/` or `data/` directory or one of the
+/// given input files changes, until interrupted. `day_args` are passed to the day binary.
+pub fn watch(day: u8, inputs: &[PathBuf], day_args: &[OsString]) -> anyhow::Result<()> {
+    let crate_dir = days::crate_dir(day);
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).context("could not watch the files")?;
+    for dir in ["src", "data"] {
+        let path = crate_dir.join(dir);
+        watcher
+            .watch(&path, RecursiveMode::Recursive)
+            .with_context(|| format!("could not watch {}", path.display()))?;
+    }
+    for input in inputs.iter().filter(|input| input.as_os_str() != "-") {
+        watcher
+            .watch(input, RecursiveMode::NonRecursive)
+            .with_context(|| format!("could not watch {}", input.display()))?;
+    }
+
+    let mut previous: Option<Answers> = None;
+    loop {
+        println!("Day {:02} - running the tests", day);
+        let tests_passed = cargo_test(day)?;
+        println!(
+            "Day {:02} - tests {}",
+            day,
+            if tests_passed { "passed" } else { "FAILED" }
+        );
+        let answers = run_day(day, day_args)?;
+        for line in answer_diff(previous.as_ref(), &answers) {
+            println!("Day {:02} - {}", day, line);
+        }
+        previous = Some(answers);
+        println!("Day {:02} - waiting for changes", day);
+        wait_for_change(&receiver)?;
+    }
+}
+
+fn workspace_manifest() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("Cargo.toml")
+}
+
+// a cargo command on the day crate, using the cargo that started the runner if any
+fn cargo_command(day: u8, subcommand: &str) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command
+        .arg(subcommand)
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(workspace_manifest())
+        .arg("--package")
+        .arg(format!("day-{:02}", day));
+    command
+}
+
+// runs the tests of the day crate with their output shown, returns whether they passed
+fn cargo_test(day: u8) -> anyhow::Result<bool> {
+    let status = cargo_command(day, "test")
+        .status()
+        .context("could not run cargo test")?;
+    Ok(status.success())
+}
+
+// runs the day binary in release mode, no answers if it does not build or fails
+fn run_day(day: u8, day_args: &[OsString]) -> anyhow::Result<Answers> {
+    let output = cargo_command(day, "run")
+        .arg("--release")
+        .arg("--")
+        .args(day_args)
+        .stderr(Stdio::inherit())
+        .output()
+        .context("could not run cargo run")?;
+    Ok(parse_answers(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// The answers of the `Solution <part>: <answer>` lines of a day binary.
+pub fn parse_answers(output: &str) -> Answers {
+    output
+        .lines()
+        .filter_map(|line| line.strip_prefix("Solution "))
+        .filter_map(|line| line.split_once(": "))
+        .map(|(part, answer)| (part.to_string(), answer.to_string()))
+        .collect()
+}
+
+/// One line per part comparing the answers with those of the previous run.
+pub fn answer_diff(previous: Option<&Answers>, answers: &Answers) -> Vec<String> {
+    let Some(previous) = previous else {
+        return answers
+            .iter()
+            .map(|(part, answer)| format!("Solution {}: {}", part, answer))
+            .collect();
+    };
+    let mut parts: Vec<&String> = previous.keys().chain(answers.keys()).collect();
+    parts.sort();
+    parts.dedup();
+    parts
+        .into_iter()
+        .map(|part| match (previous.get(part), answers.get(part)) {
+            (Some(old), Some(new)) if old == new => {
+                format!("Solution {}: {} (unchanged)", part, new)
+            }
+            (Some(old), Some(new)) => format!("Solution {}: {} (was {})", part, new, old),
+            (None, Some(new)) => format!("Solution {}: {} (new)", part, new),
+            (Some(old), None) => format!("Solution {}: no answer (was {})", part, old),
+            (None, None) => unreachable!("the part is in one of the runs"),
+        })
+        .collect()
+}
+
+// blocks until a file was created, changed or removed and no events followed for a while
+fn wait_for_change(receiver: &Receiver<notify::Result<Event>>) -> anyhow::Result<()> {
+    let is_change = |event: notify::Result<Event>| -> anyhow::Result<bool> {
+        Ok(!event.context("could not watch the files")?.kind.is_access())
+    };
+    while !is_change(receiver.recv().context("the file watcher stopped")?)? {}
+    loop {
+        match receiver.recv_timeout(QUIET_PERIOD) {
+            Ok(event) => {
+                is_change(event)?;
+            }
+            Err(RecvTimeoutError::Timeout) => return Ok(()),
+            Err(RecvTimeoutError::Disconnected) => bail!("the file watcher stopped"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::watch::{answer_diff, parse_answers, Answers};
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("The input is valid\nSolution 1: 11\nSolution 2: -31\n");
+        assert_eq!(
+            answers,
+            Answers::from([
+                ("1".to_string(), "11".to_string()),
+                ("2".to_string(), "-31".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_answer_diff() {
+        let first = parse_answers("Solution 1: 11\nSolution 2: 31\n");
+        assert_eq!(
+            answer_diff(None, &first),
+            ["Solution 1: 11", "Solution 2: 31"]
+        );
+        let second = parse_answers("Solution 1: 11\nSolution 2: 32\n");
+        assert_eq!(
+            answer_diff(Some(&first), &second),
+            ["Solution 1: 11 (unchanged)", "Solution 2: 32 (was 31)"]
+        );
+        let failed = parse_answers("Solution 1: 11\n");
+        assert_eq!(
+            answer_diff(Some(&second), &failed),
+            [
+                "Solution 1: 11 (unchanged)",
+                "Solution 2: no answer (was 32)"
+            ]
+        );
+        assert_eq!(
+            answer_diff(Some(&failed), &second),
+            ["Solution 1: 11 (unchanged)", "Solution 2: 32 (new)"]
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_01::{generator, puzzle01, puzzle02, LocationLists};
+
+fn bench_puzzles(c: &mut Criterion) {
+    c.bench_function("day 01 part 1", |b| {
+        b.iter(|| puzzle01("data/data_1").unwrap())
+    });
+    c.bench_function("day 01 part 2", |b| {
+        b.iter(|| puzzle02("data/data_1").unwrap())
+    });
+}
+
+// the baseline of the similarity score: scanning the whole right list for every left id, O(n²)
+fn similarity_score_by_scanning(location_lists: &LocationLists) -> i64 {
+    location_lists
+        .left
+        .iter()
+        .map(|left| {
+            let count = location_lists
+                .right
+                .iter()
+                .filter(|right| *right == left)
+                .count();
+            left * count as i64
+        })
+        .sum()
+}
+
+// the counter of part 2 against scanning the right list, on generated lists of growing length
+fn bench_similarity(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day 01 similarity");
+    group.sample_size(10);
+    for size in [1_000, 10_000, 100_000, 1_000_000] {
+        let location_lists: LocationLists = generator::generate(1, size).parse().unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("counter", size),
+            &location_lists,
+            |b, location_lists| b.iter(|| location_lists.similarity_score().unwrap()),
+        );
+        // scanning takes seconds per iteration for 100000 ids and minutes for a million
+        if size <= 10_000 {
+            group.bench_with_input(
+                BenchmarkId::new("scanning", size),
+                &location_lists,
+                |b, location_lists| b.iter(|| similarity_score_by_scanning(location_lists)),
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_puzzles, bench_similarity);
+criterion_main!(benches);
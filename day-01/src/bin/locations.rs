@@ -0,0 +1,105 @@
+use anyhow::Context;
+use aoc_core::{read_inputs, Part, Solution};
+use clap::{Parser, Subcommand};
+use day_01::{Day01, DistanceParams, LocationLists, LocationTable, Metric, Pairing};
+use std::{path::PathBuf, process::ExitCode};
+
+#[derive(Parser)]
+#[command(about = "Compares the location id lists of the historians")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the total distance and the similarity score of every pair of teams
+    Matrix {
+        /// Location id lists, one column per team separated by whitespace, commas or tabs, `-`
+        /// reads the standard input
+        input: PathBuf,
+    },
+    /// Print the pairs of the sorted lists, the largest discrepancies, the ids missing from one
+    /// list and the duplicate ids
+    Report {
+        /// Two location id lists separated by whitespace, `-` reads the standard input
+        input: PathBuf,
+        /// Number of largest discrepancies
+        #[arg(short, long, default_value_t = 10)]
+        top: usize,
+    },
+    /// Print part 1 of day 1 for several distance metrics and pairing strategies, like
+    /// `day-01 --part 1 --param metric=METRIC --param pairing=PAIRING`
+    Score {
+        /// Two location id lists separated by whitespace, `-` reads the standard input
+        input: PathBuf,
+        /// Distance of two ids: `absolute`, `squared` or `relative`, all metrics if omitted
+        #[arg(short, long)]
+        metric: Option<Metric>,
+        /// Which ids are paired up: `sorted`, `original` (same line) or `optimal` (minimum total
+        /// distance, at most 1000 ids for the relative metric), `sorted` and `original` if
+        /// omitted
+        #[arg(short, long)]
+        pairing: Option<Pairing>,
+    },
+}
+
+fn read_location_lists(input: PathBuf) -> anyhow::Result<LocationLists> {
+    read_inputs(&[input])?
+        .parse()
+        .context("could not parse the location ids")
+}
+
+fn run(cli: Cli) -> anyhow::Result<()> {
+    match cli.command {
+        Command::Matrix { input } => {
+            let table: LocationTable = read_inputs(&[input])?
+                .parse()
+                .context("could not parse the location ids")?;
+            println!("Total distance");
+            println!("{}", table.distance_matrix()?);
+            println!("Similarity score (rows are the left lists)");
+            print!("{}", table.similarity_matrix()?);
+        }
+        Command::Report { input, top } => {
+            print!("{}", read_location_lists(input)?.reconcile().report(top));
+        }
+        Command::Score {
+            input,
+            metric,
+            pairing,
+        } => {
+            let location_lists = read_location_lists(input)?;
+            let metrics = metric.map_or(Metric::ALL.to_vec(), |metric| vec![metric]);
+            let pairings = pairing.map_or(vec![Pairing::Sorted, Pairing::Original], |pairing| {
+                vec![pairing]
+            });
+            println!("{:<10} {:<10} total distance", "metric", "pairing");
+            for metric in metrics {
+                for pairing in &pairings {
+                    let params = DistanceParams {
+                        metric,
+                        pairing: *pairing,
+                    };
+                    println!(
+                        "{:<10} {:<10} {}",
+                        metric.to_string(),
+                        pairing.to_string(),
+                        Day01::solve(&location_lists, Part::One, &params)?
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {:?}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
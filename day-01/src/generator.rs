@@ -0,0 +1,17 @@
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` lines of location id pairs, about a third of the right ids repeat left ids.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let left: Vec<u32> = (0..size).map(|_| rng.gen_range(10000..100000)).collect();
+    let mut input = String::new();
+    for left_id in &left {
+        let right_id = match left.choose(&mut rng) {
+            Some(id) if rng.gen_ratio(1, 3) => *id,
+            _ => rng.gen_range(10000..100000),
+        };
+        input.push_str(&format!("{}   {}\n", left_id, right_id));
+    }
+    input
+}
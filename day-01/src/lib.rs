@@ -0,0 +1,305 @@
+pub mod generator;
+pub mod metric;
+pub mod report;
+pub mod stream;
+pub mod table;
+pub mod validator;
+
+use anyhow::Context;
+use aoc_core::{Answer, Location, Problem, Solution};
+pub use metric::{Distance, DistanceParams, Metric, Pairing};
+use regex::Regex;
+pub use report::{Duplicate, Pair, Reconciliation};
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    num::ParseIntError,
+    path::Path,
+    str::FromStr,
+};
+pub use stream::{LocationPairs, SimilarityCounter};
+pub use table::{ColumnMatrix, LocationTable, Separator};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum LocationListError {
+    #[error("line {line} does not contain two location ids: {text:?}")]
+    MissingIds { line: usize, text: String },
+    #[error("line {line} has {found} location ids, the first line {expected}")]
+    ColumnCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("line {line} separates the ids by {found}, the first line by {expected}")]
+    Separator {
+        line: usize,
+        expected: Separator,
+        found: Separator,
+    },
+    /// The id is no number or does not fit into an i64, only the latter has a source.
+    #[error("invalid location id {text:?} at {location}")]
+    InvalidId {
+        location: Location,
+        text: String,
+        source: Option<ParseIntError>,
+    },
+    #[error("the left list has {left} location ids, the right list {right}")]
+    ListLengths { left: usize, right: usize },
+    #[error("could not read line {line}")]
+    Io { line: usize, source: io::Error },
+}
+
+/// The two location id lists of the historians, one column each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationLists {
+    pub left: Vec<i64>,
+    pub right: Vec<i64>,
+}
+
+// two ids separated by whitespace, each with an optional minus sign
+fn line_regex() -> Regex {
+    Regex::new(r"^\s*(-?\d+)\s+(-?\d+)\s*$").expect("the line regex is valid")
+}
+
+// the rule of the parsers and the validator: digits with an optional minus sign, but no plus
+// sign, fitting into an i64
+pub(crate) fn parse_id(id: &str) -> Result<i64, Option<ParseIntError>> {
+    let digits = id.strip_prefix('-').unwrap_or(id);
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(None);
+    }
+    id.parse().map_err(Some)
+}
+
+// the left and right id of a line, `line_number` counted from 1
+fn parse_line(
+    line_regex: &Regex,
+    line_number: usize,
+    line: &str,
+) -> Result<(i64, i64), LocationListError> {
+    let parse_id = |id: &str| {
+        parse_id(id).map_err(|source| LocationListError::InvalidId {
+            location: Location::new(line_number, Location::of(line, id).column),
+            text: id.to_string(),
+            source,
+        })
+    };
+
+    let capture = line_regex
+        .captures(line)
+        .ok_or_else(|| LocationListError::MissingIds {
+            line: line_number,
+            text: line.to_string(),
+        })?;
+    Ok((parse_id(&capture[1])?, parse_id(&capture[2])?))
+}
+
+impl FromStr for LocationLists {
+    type Err = LocationListError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LocationLists::from_reader(s.as_bytes())
+    }
+}
+
+impl LocationLists {
+    /// Parses the lists line by line without reading the whole input into a string first.
+    pub fn from_reader<R>(reader: R) -> Result<Self, LocationListError>
+    where
+        R: BufRead,
+    {
+        let mut location_lists = LocationLists {
+            left: Vec::new(),
+            right: Vec::new(),
+        };
+        for pair in LocationPairs::new(reader) {
+            let (left, right) = pair?;
+            location_lists.left.push(left);
+            location_lists.right.push(right);
+        }
+        Ok(location_lists)
+    }
+
+    /// The sum of the distances of the pairs of the sorted lists. The sum is taken in i128, the
+    /// distance of two ids may not even fit into an i64.
+    pub fn total_distance(&self) -> anyhow::Result<i64> {
+        let mut left_lines = self.left.clone();
+        let mut right_lines = self.right.clone();
+        left_lines.sort_unstable();
+        right_lines.sort_unstable();
+
+        let total: i128 = left_lines
+            .iter()
+            .zip(right_lines.iter())
+            .map(|(left, right)| i128::from(left.abs_diff(*right)))
+            .sum();
+        i64::try_from(total).context("the total distance does not fit into an i64")
+    }
+
+    /// Every left id multiplied by the number of its occurrences in the right list, summed up
+    /// by a [`SimilarityCounter`], O(n). The lists may have different lengths.
+    pub fn similarity_score(&self) -> anyhow::Result<i64> {
+        let mut counter = SimilarityCounter::default();
+        for left in &self.left {
+            counter.add_left(*left)?;
+        }
+        for right in &self.right {
+            counter.add_right(*right)?;
+        }
+        counter.score()
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = LocationLists;
+    type Params = DistanceParams;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn parse_reader<R>(reader: R) -> anyhow::Result<Self::Input>
+    where
+        R: Read,
+    {
+        Ok(LocationLists::from_reader(BufReader::new(reader))?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(location_lists: &LocationLists, params: &DistanceParams) -> anyhow::Result<Answer> {
+        Ok(location_lists
+            .total_distance_by(params.metric, params.pairing)?
+            .into())
+    }
+
+    // the similarity score does not depend on the distance parameters
+    fn part2(location_lists: &LocationLists, _: &DistanceParams) -> anyhow::Result<Answer> {
+        Ok(location_lists.similarity_score()?.into())
+    }
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    LocationLists::from_reader(BufReader::new(reader))?.total_distance()
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<i64> {
+    let location_lists: LocationLists = input.parse()?;
+    location_lists.total_distance()
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    // a single pass over the lines, only the counts of the distinct ids are kept
+    let mut counter = SimilarityCounter::default();
+    for pair in LocationPairs::new(BufReader::new(reader)) {
+        let (left, right) = pair?;
+        counter.add(left, right)?;
+    }
+    counter.score()
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<i64> {
+    puzzle02_from_reader(input.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 11)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 11)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1").unwrap(), 31)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use crate::{LocationListError, LocationLists};
+        use aoc_core::Location;
+
+        let error = "3   4\nfour   3\n".parse::<LocationLists>().unwrap_err();
+        assert!(matches!(
+            error,
+            LocationListError::MissingIds { line: 2, .. }
+        ));
+
+        let error = "3   4\n4   99999999999999999999\n"
+            .parse::<LocationLists>()
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            LocationListError::InvalidId { location, .. } if location == Location::new(2, 5)
+        ));
+
+        // blank lines may only end the input
+        let location_lists = "3   4\n4   3\n\n  \n".parse::<LocationLists>().unwrap();
+        assert_eq!(location_lists.left, [3, 4]);
+        let error = "3   4\n\n4   3\n".parse::<LocationLists>().unwrap_err();
+        assert!(matches!(
+            error,
+            LocationListError::MissingIds { line: 2, .. }
+        ));
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("3   4\n4\n5   x\n"),
+            [
+                Problem::at_line(2, "expected 2 location ids, found 1"),
+                Problem::at_line(3, "invalid location id \"x\"")
+            ]
+        );
+
+        // the parser rejects a plus sign as well
+        assert_eq!(
+            validate("+3   4\n"),
+            [Problem::at_line(1, "invalid location id \"+3\"")]
+        );
+        assert!("+3   4\n".parse::<crate::LocationLists>().is_err());
+        assert_eq!(validate("3   4\n\n"), []);
+    }
+}
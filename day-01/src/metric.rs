@@ -0,0 +1,307 @@
+use crate::{LocationListError, LocationLists};
+use anyhow::{bail, Context};
+use aoc_core::{Answer, Parameters};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// How far apart two location ids are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Metric {
+    /// `|left - right|`, the metric of the puzzle
+    #[default]
+    Absolute,
+    /// `(left - right)²`, large differences weigh more
+    Squared,
+    /// `|left - right| / max(|left|, |right|)`, the difference as a fraction of the larger id
+    Relative,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 3] = [Metric::Absolute, Metric::Squared, Metric::Relative];
+
+    pub fn distance(self, left: i64, right: i64) -> Distance {
+        let difference = left.abs_diff(right);
+        match self {
+            Metric::Absolute => Distance::Integer(difference.into()),
+            // (2⁶⁴ - 1)² fits into a u128
+            Metric::Squared => Distance::Integer(u128::from(difference) * u128::from(difference)),
+            Metric::Relative => match left.unsigned_abs().max(right.unsigned_abs()) {
+                0 => Distance::Fraction(0.0),
+                larger => Distance::Fraction(difference as f64 / larger as f64),
+            },
+        }
+    }
+
+    fn zero(self) -> Distance {
+        match self {
+            Metric::Absolute | Metric::Squared => Distance::Integer(0),
+            Metric::Relative => Distance::Fraction(0.0),
+        }
+    }
+}
+
+/// A distance under a [`Metric`] or a sum of them, exact for the absolute and the squared metric.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Distance {
+    Integer(u128),
+    Fraction(f64),
+}
+
+impl Distance {
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Distance::Integer(value) => value as f64,
+            Distance::Fraction(value) => value,
+        }
+    }
+
+    // `None` if the sum overflows, distances of different metrics are not added up
+    fn checked_add(self, other: Distance) -> Option<Distance> {
+        match (self, other) {
+            (Distance::Integer(left), Distance::Integer(right)) => {
+                left.checked_add(right).map(Distance::Integer)
+            }
+            (Distance::Fraction(left), Distance::Fraction(right)) => {
+                Some(Distance::Fraction(left + right))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Distance::Integer(value) => write!(f, "{}", value),
+            Distance::Fraction(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<Distance> for Answer {
+    fn from(distance: Distance) -> Self {
+        match distance {
+            Distance::Integer(value) => Answer::Unsigned(value),
+            Distance::Fraction(value) => Answer::Decimal(value),
+        }
+    }
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Metric::Absolute => write!(f, "absolute"),
+            Metric::Squared => write!(f, "squared"),
+            Metric::Relative => write!(f, "relative"),
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "absolute" => Ok(Metric::Absolute),
+            "squared" => Ok(Metric::Squared),
+            "relative" => Ok(Metric::Relative),
+            _ => bail!(
+                "unknown metric `{}`, expected `absolute`, `squared` or `relative`",
+                s
+            ),
+        }
+    }
+}
+
+/// Which left id is compared with which right id.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Pairing {
+    /// The smallest ids of both lists, then the second smallest and so on, as in the puzzle
+    #[default]
+    Sorted,
+    /// The pairs with the minimum total distance under the metric
+    Optimal,
+    /// The ids on the same line
+    Original,
+}
+
+impl Pairing {
+    pub const ALL: [Pairing; 3] = [Pairing::Sorted, Pairing::Optimal, Pairing::Original];
+}
+
+impl Display for Pairing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Pairing::Sorted => write!(f, "sorted"),
+            Pairing::Optimal => write!(f, "optimal"),
+            Pairing::Original => write!(f, "original"),
+        }
+    }
+}
+
+impl FromStr for Pairing {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sorted" => Ok(Pairing::Sorted),
+            "optimal" => Ok(Pairing::Optimal),
+            "original" => Ok(Pairing::Original),
+            _ => bail!(
+                "unknown pairing `{}`, expected `sorted`, `optimal` or `original`",
+                s
+            ),
+        }
+    }
+}
+
+/// The parameters of day 1, how part 1 adds up the distances. The defaults are the rules of the
+/// puzzle, e.g. `--param metric=squared --param pairing=original` changes them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DistanceParams {
+    pub metric: Metric,
+    pub pairing: Pairing,
+}
+
+impl Parameters for DistanceParams {
+    fn set(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "metric" => self.metric = value.parse()?,
+            "pairing" => self.pairing = value.parse()?,
+            _ => bail!(
+                "unknown parameter `{}`, expected `metric` or `pairing`",
+                name
+            ),
+        }
+        Ok(())
+    }
+}
+
+/// The longest lists of the optimal pairing under the relative metric, which takes O(n³) time
+/// and an n × n cost matrix.
+pub const MAX_OPTIMAL_PAIRING_LENGTH: usize = 1000;
+
+impl LocationLists {
+    /// The `(left, right)` pairs of the lists, which must have the same length. The optimal
+    /// pairing of the relative metric takes O(n³) and is refused for lists longer than
+    /// [`MAX_OPTIMAL_PAIRING_LENGTH`], the other pairings ignore the metric.
+    pub fn pairs(&self, metric: Metric, pairing: Pairing) -> anyhow::Result<Vec<(i64, i64)>> {
+        if self.left.len() != self.right.len() {
+            return Err(LocationListError::ListLengths {
+                left: self.left.len(),
+                right: self.right.len(),
+            }
+            .into());
+        }
+        Ok(match (pairing, metric) {
+            // the sorted pairing minimizes the sum of a convex function of the differences
+            (Pairing::Sorted, _) | (Pairing::Optimal, Metric::Absolute | Metric::Squared) => {
+                let mut left_lines = self.left.clone();
+                let mut right_lines = self.right.clone();
+                left_lines.sort_unstable();
+                right_lines.sort_unstable();
+                left_lines.into_iter().zip(right_lines).collect()
+            }
+            (Pairing::Optimal, Metric::Relative) => {
+                if self.left.len() > MAX_OPTIMAL_PAIRING_LENGTH {
+                    bail!(
+                        "the relative optimal pairing is limited to {} ids, the lists have {}",
+                        MAX_OPTIMAL_PAIRING_LENGTH,
+                        self.left.len()
+                    );
+                }
+                let costs: Vec<Vec<f64>> = self
+                    .left
+                    .iter()
+                    .map(|left| {
+                        self.right
+                            .iter()
+                            .map(|right| metric.distance(*left, *right).as_f64())
+                            .collect()
+                    })
+                    .collect();
+                self.left
+                    .iter()
+                    .zip(minimum_cost_assignment(&costs))
+                    .map(|(left, column)| (*left, self.right[column]))
+                    .collect()
+            }
+            (Pairing::Original, _) => self
+                .left
+                .iter()
+                .copied()
+                .zip(self.right.iter().copied())
+                .collect(),
+        })
+    }
+
+    /// The sum of the distances of the pairs, [`LocationLists::total_distance`] with the
+    /// absolute metric and the sorted pairing.
+    pub fn total_distance_by(&self, metric: Metric, pairing: Pairing) -> anyhow::Result<Distance> {
+        self.pairs(metric, pairing)?
+            .into_iter()
+            .try_fold(metric.zero(), |total, (left, right)| {
+                total.checked_add(metric.distance(left, right))
+            })
+            .context("the total distance overflows")
+    }
+}
+
+// the column assigned to every row of a square cost matrix so that the sum of the costs is
+// minimal, using the Hungarian algorithm with row and column potentials
+fn minimum_cost_assignment(costs: &[Vec<f64>]) -> Vec<usize> {
+    let n = costs.len();
+    // indices from 1, row and column 0 are the unassigned sentinel
+    let mut row_potentials = vec![0.0; n + 1];
+    let mut column_potentials = vec![0.0; n + 1];
+    let mut column_rows = vec![0; n + 1];
+    let mut previous_columns = vec![0; n + 1];
+    for row in 1..=n {
+        column_rows[0] = row;
+        let mut column = 0;
+        let mut min_slacks = vec![f64::INFINITY; n + 1];
+        let mut is_used = vec![false; n + 1];
+        // grow an alternating path from the new row until it reaches a free column
+        while column_rows[column] != 0 {
+            is_used[column] = true;
+            let path_row = column_rows[column];
+            let mut delta = f64::INFINITY;
+            let mut next_column = 0;
+            for j in (1..=n).filter(|j| !is_used[*j]) {
+                let slack =
+                    costs[path_row - 1][j - 1] - row_potentials[path_row] - column_potentials[j];
+                if slack < min_slacks[j] {
+                    min_slacks[j] = slack;
+                    previous_columns[j] = column;
+                }
+                if min_slacks[j] < delta {
+                    delta = min_slacks[j];
+                    next_column = j;
+                }
+            }
+            for j in 0..=n {
+                if is_used[j] {
+                    row_potentials[column_rows[j]] += delta;
+                    column_potentials[j] -= delta;
+                } else {
+                    min_slacks[j] -= delta;
+                }
+            }
+            column = next_column;
+        }
+        // flip the assignments along the path
+        while column != 0 {
+            let previous_column = previous_columns[column];
+            column_rows[column] = column_rows[previous_column];
+            column = previous_column;
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    for (column, row) in column_rows.iter().enumerate().skip(1) {
+        assignment[row - 1] = column - 1;
+    }
+    assignment
+}
@@ -0,0 +1,201 @@
+use crate::LocationLists;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display},
+};
+
+/// A left and a right id paired up by their rank in the sorted lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    pub left: i64,
+    pub right: i64,
+    pub distance: u64,
+}
+
+/// How often an id occurs in the lists, for the ids occurring more than once in one of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Duplicate {
+    pub id: i64,
+    pub left: usize,
+    pub right: usize,
+}
+
+/// The differences between the two lists of the historians.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reconciliation {
+    /// The pairs of the sorted lists, in order.
+    pub pairs: Vec<Pair>,
+    /// The distinct ids of the left list that are not in the right list, ascending.
+    pub only_left: Vec<i64>,
+    /// The distinct ids of the right list that are not in the left list, ascending.
+    pub only_right: Vec<i64>,
+    /// The ids occurring more than once in a list, ascending.
+    pub duplicates: Vec<Duplicate>,
+}
+
+fn count_ids(ids: &[i64]) -> BTreeMap<i64, usize> {
+    let mut counts = BTreeMap::new();
+    for id in ids {
+        *counts.entry(*id).or_insert(0) += 1;
+    }
+    counts
+}
+
+impl LocationLists {
+    /// Pairs up the sorted lists and collects the ids that do not match up.
+    pub fn reconcile(&self) -> Reconciliation {
+        let mut left_lines = self.left.clone();
+        let mut right_lines = self.right.clone();
+        left_lines.sort_unstable();
+        right_lines.sort_unstable();
+        let pairs = left_lines
+            .iter()
+            .zip(&right_lines)
+            .map(|(left, right)| Pair {
+                left: *left,
+                right: *right,
+                distance: left.abs_diff(*right),
+            })
+            .collect();
+
+        let left_counts = count_ids(&self.left);
+        let right_counts = count_ids(&self.right);
+        let ids: BTreeSet<i64> = left_counts
+            .keys()
+            .chain(right_counts.keys())
+            .copied()
+            .collect();
+        let mut reconciliation = Reconciliation {
+            pairs,
+            only_left: Vec::new(),
+            only_right: Vec::new(),
+            duplicates: Vec::new(),
+        };
+        for id in ids {
+            let left = left_counts.get(&id).copied().unwrap_or(0);
+            let right = right_counts.get(&id).copied().unwrap_or(0);
+            if right == 0 {
+                reconciliation.only_left.push(id);
+            }
+            if left == 0 {
+                reconciliation.only_right.push(id);
+            }
+            if left > 1 || right > 1 {
+                reconciliation
+                    .duplicates
+                    .push(Duplicate { id, left, right });
+            }
+        }
+        reconciliation
+    }
+}
+
+impl Reconciliation {
+    /// The sum of the distances, exact for any number of pairs of 64-bit ids.
+    pub fn total_distance(&self) -> u128 {
+        self.pairs
+            .iter()
+            .map(|pair| u128::from(pair.distance))
+            .sum()
+    }
+
+    /// The `number` pairs with the largest distances, pairs with the same distance in the order
+    /// of the sorted lists.
+    pub fn largest_discrepancies(&self, number: usize) -> Vec<Pair> {
+        let mut pairs = self.pairs.clone();
+        pairs.sort_by_key(|pair| Reverse(pair.distance));
+        pairs.truncate(number);
+        pairs
+    }
+
+    /// The report as text, listing the `top` largest discrepancies.
+    pub fn report(&self, top: usize) -> Report<'_> {
+        Report {
+            reconciliation: self,
+            top,
+        }
+    }
+}
+
+/// The text of a [`Reconciliation`], see [`Reconciliation::report`].
+pub struct Report<'a> {
+    reconciliation: &'a Reconciliation,
+    top: usize,
+}
+
+// the values separated by commas, `none` without values
+fn join<T: Display>(values: &[T]) -> String {
+    if values.is_empty() {
+        "none".to_string()
+    } else {
+        values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl Display for Duplicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} left, {} right)", self.id, self.left, self.right)
+    }
+}
+
+impl Report<'_> {
+    fn write_pairs(&self, f: &mut fmt::Formatter<'_>, pairs: &[Pair]) -> fmt::Result {
+        let width = self
+            .reconciliation
+            .pairs
+            .iter()
+            .flat_map(|pair| {
+                [
+                    pair.left.to_string(),
+                    pair.right.to_string(),
+                    pair.distance.to_string(),
+                ]
+            })
+            .map(|value| value.len())
+            .chain(["distance".len()])
+            .max()
+            .unwrap_or(0);
+        writeln!(
+            f,
+            "{:>width$} {:>width$} {:>width$}",
+            "left", "right", "distance"
+        )?;
+        for pair in pairs {
+            writeln!(
+                f,
+                "{:>width$} {:>width$} {:>width$}",
+                pair.left, pair.right, pair.distance
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reconciliation = self.reconciliation;
+        writeln!(f, "Pairs of the sorted lists")?;
+        self.write_pairs(f, &reconciliation.pairs)?;
+        writeln!(f)?;
+        writeln!(f, "Largest discrepancies")?;
+        self.write_pairs(f, &reconciliation.largest_discrepancies(self.top))?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Only in the left list: {}",
+            join(&reconciliation.only_left)
+        )?;
+        writeln!(
+            f,
+            "Only in the right list: {}",
+            join(&reconciliation.only_right)
+        )?;
+        writeln!(f, "Duplicates: {}", join(&reconciliation.duplicates))?;
+        writeln!(f, "Total distance: {}", reconciliation.total_distance())
+    }
+}
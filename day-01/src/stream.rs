@@ -0,0 +1,120 @@
+use crate::{line_regex, parse_line, LocationListError};
+use anyhow::Context;
+use regex::Regex;
+use std::{collections::HashMap, io::BufRead};
+
+/// The `(left, right)` id pairs of a reader, one line at a time. Unreadable lines end the
+/// iteration with [`LocationListError::Io`], malformed lines are reported like in the parser
+/// of [`crate::LocationLists`]. Blank lines at the end of the input are skipped, a blank line
+/// followed by ids is malformed.
+pub struct LocationPairs<R> {
+    reader: R,
+    line_regex: Regex,
+    line: String,
+    line_number: usize,
+    // the first of the blank lines read since the last ids
+    blank_line: Option<usize>,
+    is_done: bool,
+}
+
+impl<R> LocationPairs<R>
+where
+    R: BufRead,
+{
+    pub fn new(reader: R) -> Self {
+        LocationPairs {
+            reader,
+            line_regex: line_regex(),
+            line: String::new(),
+            line_number: 0,
+            blank_line: None,
+            is_done: false,
+        }
+    }
+}
+
+impl<R> Iterator for LocationPairs<R>
+where
+    R: BufRead,
+{
+    type Item = Result<(i64, i64), LocationListError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.is_done {
+            self.line.clear();
+            self.line_number += 1;
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => self.is_done = true,
+                Ok(_) if self.line.trim().is_empty() => {
+                    self.blank_line.get_or_insert(self.line_number);
+                }
+                Ok(_) => {
+                    if let Some(blank_line) = self.blank_line {
+                        self.is_done = true;
+                        return Some(Err(LocationListError::MissingIds {
+                            line: blank_line,
+                            text: String::new(),
+                        }));
+                    }
+                    let line = self.line.trim_end_matches(['\n', '\r']);
+                    return Some(parse_line(&self.line_regex, self.line_number, line));
+                }
+                Err(source) => {
+                    self.is_done = true;
+                    return Some(Err(LocationListError::Io {
+                        line: self.line_number,
+                        source,
+                    }));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The similarity score of the lists, updated id by id in a single pass. It keeps how often
+/// every distinct id occurred in each list, so its memory is O(distinct ids): it does not grow
+/// with repeated ids, but lists of mostly distinct ids need memory in proportion to their length.
+/// The only counting of part 2, [`crate::LocationLists::similarity_score`] and
+/// [`crate::puzzle02_from_reader`] both use it.
+#[derive(Debug, Clone, Default)]
+pub struct SimilarityCounter {
+    // the occurrences in the left and in the right list
+    counts: HashMap<i64, (u64, u64)>,
+    score: i128,
+}
+
+impl SimilarityCounter {
+    /// Adds the ids of a line. Every left id and right id of the same value adds the id to the
+    /// score once, whichever of the two is read last.
+    pub fn add(&mut self, left: i64, right: i64) -> anyhow::Result<()> {
+        self.add_left(left)?;
+        self.add_right(right)
+    }
+
+    pub fn add_left(&mut self, left: i64) -> anyhow::Result<()> {
+        let counts = self.counts.entry(left).or_default();
+        counts.0 += 1;
+        self.score = checked_add_product(self.score, left, counts.1)?;
+        Ok(())
+    }
+
+    pub fn add_right(&mut self, right: i64) -> anyhow::Result<()> {
+        let counts = self.counts.entry(right).or_default();
+        counts.1 += 1;
+        self.score = checked_add_product(self.score, right, counts.0)?;
+        Ok(())
+    }
+
+    pub fn score(&self) -> anyhow::Result<i64> {
+        i64::try_from(self.score).context("the similarity score does not fit into an i64")
+    }
+}
+
+// `score + id * count` for the similarity score, which is summed up in i128
+fn checked_add_product(score: i128, id: i64, count: u64) -> anyhow::Result<i128> {
+    i128::from(id)
+        .checked_mul(i128::from(count))
+        .and_then(|product| score.checked_add(product))
+        .context("the similarity score overflows")
+}
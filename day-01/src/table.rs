@@ -0,0 +1,190 @@
+use crate::{parse_id, LocationListError, LocationLists};
+use aoc_core::Location;
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// The location id lists of any number of teams, one column each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationTable {
+    columns: Vec<Vec<i64>>,
+}
+
+/// How the ids of a line of a [`LocationTable`] are separated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    /// CSV
+    Comma,
+    /// TSV
+    Tab,
+    /// Any whitespace, like the lists of the puzzle.
+    Whitespace,
+}
+
+impl Separator {
+    /// The separator of a line: commas if it has any, otherwise tabs if it has any.
+    pub fn of(line: &str) -> Self {
+        if line.contains(',') {
+            Separator::Comma
+        } else if line.contains('\t') {
+            Separator::Tab
+        } else {
+            Separator::Whitespace
+        }
+    }
+
+    fn split(self, line: &str) -> Vec<&str> {
+        match self {
+            Separator::Comma => line.split(',').map(str::trim).collect(),
+            Separator::Tab => line.split('\t').map(str::trim).collect(),
+            Separator::Whitespace => line.split_whitespace().collect(),
+        }
+    }
+}
+
+impl Display for Separator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Separator::Comma => write!(f, "commas"),
+            Separator::Tab => write!(f, "tabs"),
+            Separator::Whitespace => write!(f, "whitespace"),
+        }
+    }
+}
+
+impl FromStr for LocationTable {
+    type Err = LocationListError;
+
+    // every line holds one id per column, the first line determines the separator and the
+    // number of columns, blank lines may only end the input
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines: Vec<&str> = s.lines().collect();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+
+        let separator = lines
+            .first()
+            .map_or(Separator::Whitespace, |line| Separator::of(line));
+        let mut columns: Vec<Vec<i64>> = Vec::new();
+        for (index, line) in lines.into_iter().enumerate() {
+            if line.trim().is_empty() {
+                return Err(LocationListError::MissingIds {
+                    line: index + 1,
+                    text: line.to_string(),
+                });
+            }
+            let found = Separator::of(line);
+            if found != separator {
+                return Err(LocationListError::Separator {
+                    line: index + 1,
+                    expected: separator,
+                    found,
+                });
+            }
+            let ids = separator.split(line);
+            if index == 0 {
+                columns = vec![Vec::new(); ids.len()];
+            } else if ids.len() != columns.len() {
+                return Err(LocationListError::ColumnCount {
+                    line: index + 1,
+                    expected: columns.len(),
+                    found: ids.len(),
+                });
+            }
+            for (column, id) in columns.iter_mut().zip(ids) {
+                column.push(parse_id(id).map_err(|source| LocationListError::InvalidId {
+                    location: Location::of(s, id),
+                    text: id.to_string(),
+                    source,
+                })?);
+            }
+        }
+        Ok(LocationTable { columns })
+    }
+}
+
+impl LocationTable {
+    pub fn columns(&self) -> &[Vec<i64>] {
+        &self.columns
+    }
+
+    /// The lists of two columns, counted from 0.
+    pub fn lists(&self, left: usize, right: usize) -> LocationLists {
+        LocationLists {
+            left: self.columns[left].clone(),
+            right: self.columns[right].clone(),
+        }
+    }
+
+    /// The total distance of the sorted lists of every pair of columns.
+    pub fn distance_matrix(&self) -> anyhow::Result<ColumnMatrix> {
+        self.matrix(LocationLists::total_distance)
+    }
+
+    /// The similarity score of every pair of columns, the row is the left list.
+    pub fn similarity_matrix(&self) -> anyhow::Result<ColumnMatrix> {
+        self.matrix(LocationLists::similarity_score)
+    }
+
+    fn matrix<F>(&self, compare: F) -> anyhow::Result<ColumnMatrix>
+    where
+        F: Fn(&LocationLists) -> anyhow::Result<i64>,
+    {
+        let number_of_columns = self.columns.len();
+        let mut values = Vec::with_capacity(number_of_columns);
+        for left in 0..number_of_columns {
+            values.push(
+                (0..number_of_columns)
+                    .map(|right| compare(&self.lists(left, right)))
+                    .collect::<anyhow::Result<Vec<i64>>>()?,
+            );
+        }
+        Ok(ColumnMatrix { values })
+    }
+}
+
+/// A value for every pair of columns of a [`LocationTable`], printed as a table with the
+/// columns counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMatrix {
+    values: Vec<Vec<i64>>,
+}
+
+impl ColumnMatrix {
+    /// The value of the lists of two columns, counted from 0.
+    pub fn get(&self, left: usize, right: usize) -> i64 {
+        self.values[left][right]
+    }
+
+    pub fn rows(&self) -> &[Vec<i64>] {
+        &self.values
+    }
+}
+
+impl Display for ColumnMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .values
+            .iter()
+            .flatten()
+            .map(|value| value.to_string().len())
+            .chain([self.values.len().to_string().len()])
+            .max()
+            .unwrap_or(1);
+        write!(f, "{:>width$}", "", width = width)?;
+        for column in 1..=self.values.len() {
+            write!(f, " {:>width$}", column, width = width)?;
+        }
+        writeln!(f)?;
+        for (row, values) in self.values.iter().enumerate() {
+            write!(f, "{:>width$}", row + 1, width = width)?;
+            for value in values {
+                write!(f, " {:>width$}", value, width = width)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
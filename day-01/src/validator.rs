@@ -0,0 +1,27 @@
+use crate::parse_id;
+use aoc_core::Problem;
+
+/// Every line holds two location ids separated by whitespace, blank lines may only end the input.
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    for (index, line) in input.trim_end().lines().enumerate() {
+        let ids = line.split_whitespace().count();
+        if ids != 2 {
+            problems.push(Problem::at_line(
+                index + 1,
+                format!("expected 2 location ids, found {}", ids),
+            ));
+        }
+        problems.extend(
+            line.split_whitespace()
+                .filter(|token| parse_id(token).is_err())
+                .map(|token| {
+                    Problem::at_line(index + 1, format!("invalid location id {:?}", token))
+                }),
+        );
+    }
+    if input.trim().is_empty() {
+        problems.push(Problem::new("there are no location ids"));
+    }
+    problems
+}
@@ -0,0 +1,330 @@
+use day_01::{
+    generator, metric::MAX_OPTIMAL_PAIRING_LENGTH, validator::validate, Distance, Duplicate,
+    LocationListError, LocationLists, LocationPairs, LocationTable, Metric, Pair, Pairing,
+    Separator, SimilarityCounter,
+};
+
+#[test]
+fn test_location_lists() {
+    let location_lists: LocationLists = include_str!("../data/test_data_1").parse().unwrap();
+    assert_eq!(location_lists.left, [3, 4, 2, 1, 3, 3]);
+    assert_eq!(location_lists.right, [4, 3, 5, 3, 9, 3]);
+    assert_eq!(location_lists.total_distance().unwrap(), 11);
+    assert_eq!(location_lists.similarity_score().unwrap(), 31);
+}
+
+#[test]
+fn test_location_table() {
+    let input = include_str!("../data/test_data_columns_1");
+    let table: LocationTable = input.parse().unwrap();
+    assert_eq!(table.columns().len(), 3);
+    assert_eq!(table.columns()[2], [3, 9, 1, 4, 3, 5]);
+    let csv: LocationTable = input.replace("   ", ",").parse().unwrap();
+    let tsv: LocationTable = input.replace("   ", "\t").parse().unwrap();
+    assert_eq!(csv, table);
+    assert_eq!(tsv, table);
+
+    let distances = table.distance_matrix().unwrap();
+    assert_eq!(distances.rows(), [[0, 11, 9], [11, 0, 2], [9, 2, 0]]);
+    let similarities = table.similarity_matrix().unwrap();
+    assert_eq!(similarities.get(0, 1), 31);
+    assert_eq!(similarities.get(1, 0), 31);
+    assert_eq!(similarities.get(1, 2), 36);
+    assert_eq!(
+        distances.to_string(),
+        "    1  2  3\n 1  0 11  9\n 2 11  0  2\n 3  9  2  0\n"
+    );
+}
+
+#[test]
+fn test_location_table_errors() {
+    let error = "1, 2, 3\n4, 5\n".parse::<LocationTable>().unwrap_err();
+    assert!(matches!(
+        error,
+        LocationListError::ColumnCount {
+            line: 2,
+            expected: 3,
+            found: 2
+        }
+    ));
+    let error = "1\t2\n4\tx\n".parse::<LocationTable>().unwrap_err();
+    assert!(matches!(error, LocationListError::InvalidId { text, .. } if text == "x"));
+
+    // the first line fixes the separator
+    let error = "1,2\n3\t4\n5   6\n".parse::<LocationTable>().unwrap_err();
+    assert!(matches!(
+        error,
+        LocationListError::Separator {
+            line: 2,
+            expected: Separator::Comma,
+            found: Separator::Tab
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "line 2 separates the ids by tabs, the first line by commas"
+    );
+
+    // the ids follow the rule of the validator
+    let error = "1,+2\n".parse::<LocationTable>().unwrap_err();
+    assert!(matches!(
+        error,
+        LocationListError::InvalidId { text, source: None, .. } if text == "+2"
+    ));
+    assert_eq!(validate("1   +2\n").len(), 1);
+
+    // blank lines may end the input
+    let table: LocationTable = "1,2\n3,4\n\n".parse().unwrap();
+    assert_eq!(table.columns(), [[1, 3], [2, 4]]);
+    let error = "1,2\n\n3,4\n".parse::<LocationTable>().unwrap_err();
+    assert!(matches!(
+        error,
+        LocationListError::MissingIds { line: 2, .. }
+    ));
+}
+
+#[test]
+fn test_reconcile() {
+    let location_lists: LocationLists = include_str!("../data/test_data_1").parse().unwrap();
+    let reconciliation = location_lists.reconcile();
+    assert_eq!(reconciliation.pairs.len(), 6);
+    assert_eq!(
+        reconciliation.total_distance(),
+        location_lists.total_distance().unwrap() as u128
+    );
+    let pair = |left, right, distance| Pair {
+        left,
+        right,
+        distance,
+    };
+    assert_eq!(
+        reconciliation.largest_discrepancies(3),
+        [pair(4, 9, 5), pair(1, 3, 2), pair(3, 5, 2)]
+    );
+    assert_eq!(reconciliation.only_left, [1, 2]);
+    assert_eq!(reconciliation.only_right, [5, 9]);
+    assert_eq!(
+        reconciliation.duplicates,
+        [Duplicate {
+            id: 3,
+            left: 3,
+            right: 3
+        }]
+    );
+    let report = reconciliation.report(1).to_string();
+    assert!(report.contains(
+        "Largest discrepancies\n    left    right distance\n       4        9        5\n\n"
+    ));
+    assert!(report.ends_with("Duplicates: 3 (3 left, 3 right)\nTotal distance: 11\n"));
+}
+
+#[test]
+fn test_distance_params() {
+    use aoc_core::{Answer, Parameters, Part, Solution};
+    use day_01::{Day01, DistanceParams};
+
+    let location_lists: LocationLists = include_str!("../data/test_data_1").parse().unwrap();
+    let mut params = DistanceParams::default();
+    assert_eq!(
+        Day01::solve(&location_lists, Part::One, &params).unwrap(),
+        Answer::Unsigned(11)
+    );
+    params.set("metric", "squared").unwrap();
+    params.set("pairing", "original").unwrap();
+    assert_eq!(
+        params,
+        DistanceParams {
+            metric: Metric::Squared,
+            pairing: Pairing::Original
+        }
+    );
+    assert_eq!(
+        Day01::solve(&location_lists, Part::One, &params).unwrap(),
+        Answer::Unsigned(51)
+    );
+    assert_eq!(
+        Day01::solve(&location_lists, Part::Two, &params).unwrap(),
+        Answer::Signed(31)
+    );
+    params.set("metric", "relative").unwrap();
+    assert!(matches!(
+        Day01::solve(&location_lists, Part::One, &params).unwrap(),
+        Answer::Decimal(_)
+    ));
+    assert!(params.set("metric", "euclidean").is_err());
+    assert!(params.set("blinks", "25").is_err());
+}
+
+#[test]
+fn test_metrics_and_pairings() {
+    let location_lists: LocationLists = include_str!("../data/test_data_1").parse().unwrap();
+    let total = |metric, pairing| location_lists.total_distance_by(metric, pairing).unwrap();
+    assert_eq!(
+        total(Metric::Absolute, Pairing::Sorted),
+        Distance::Integer(11)
+    );
+    assert_eq!(
+        total(Metric::Absolute, Pairing::Optimal),
+        Distance::Integer(11)
+    );
+    assert_eq!(
+        total(Metric::Absolute, Pairing::Original),
+        Distance::Integer(13)
+    );
+    assert_eq!(
+        total(Metric::Squared, Pairing::Sorted),
+        Distance::Integer(35)
+    );
+    assert_eq!(
+        total(Metric::Squared, Pairing::Original),
+        Distance::Integer(51)
+    );
+    assert!(total(Metric::Relative, Pairing::Optimal) < total(Metric::Relative, Pairing::Sorted));
+    assert_eq!(Metric::Relative.distance(2, 5), Distance::Fraction(0.6));
+    assert_eq!(Metric::Relative.distance(0, 0), Distance::Fraction(0.0));
+    assert_eq!(
+        location_lists
+            .pairs(Metric::Absolute, Pairing::Original)
+            .unwrap()[..2],
+        [(3, 4), (4, 3)]
+    );
+    assert_eq!("squared".parse::<Metric>().unwrap(), Metric::Squared);
+    assert_eq!("optimal".parse::<Pairing>().unwrap(), Pairing::Optimal);
+    assert!("euclidean".parse::<Metric>().is_err());
+
+    let extreme_ids = LocationLists {
+        left: vec![i64::MIN, i64::MAX],
+        right: vec![i64::MAX, i64::MIN],
+    };
+    assert_eq!(
+        extreme_ids
+            .total_distance_by(Metric::Absolute, Pairing::Original)
+            .unwrap(),
+        Distance::Integer(2 * u128::from(u64::MAX))
+    );
+    assert_eq!(
+        Metric::Squared.distance(i64::MIN, i64::MAX),
+        Distance::Integer(u128::from(u64::MAX).pow(2))
+    );
+    assert!(extreme_ids
+        .total_distance_by(Metric::Squared, Pairing::Original)
+        .is_err());
+
+    let long_lists: LocationLists = generator::generate(5, MAX_OPTIMAL_PAIRING_LENGTH + 1)
+        .parse()
+        .unwrap();
+    assert_eq!(
+        long_lists
+            .total_distance_by(Metric::Absolute, Pairing::Optimal)
+            .unwrap(),
+        Distance::Integer(long_lists.total_distance().unwrap() as u128)
+    );
+    assert!(long_lists
+        .total_distance_by(Metric::Relative, Pairing::Optimal)
+        .is_err());
+
+    let unequal_lists = LocationLists {
+        left: vec![1, 2],
+        right: vec![1],
+    };
+    let error = unequal_lists
+        .total_distance_by(Metric::Absolute, Pairing::Optimal)
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<LocationListError>(),
+        Some(LocationListError::ListLengths { left: 2, right: 1 })
+    ));
+}
+
+#[test]
+fn test_location_pairs() {
+    let input = include_str!("../data/test_data_1");
+    let pairs: Vec<(i64, i64)> = LocationPairs::new(input.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let location_lists: LocationLists = input.parse().unwrap();
+    assert_eq!(
+        pairs,
+        location_lists
+            .pairs(Metric::Absolute, Pairing::Original)
+            .unwrap()
+    );
+    assert_eq!(
+        LocationLists::from_reader(input.as_bytes()).unwrap(),
+        location_lists
+    );
+
+    let location_lists: LocationLists = "-3   4\r\n9223372036854775807   -9223372036854775808\n"
+        .parse()
+        .unwrap();
+    assert_eq!(location_lists.left, [-3, i64::MAX]);
+    assert_eq!(location_lists.right, [4, i64::MIN]);
+
+    let errors: Vec<_> = LocationPairs::new("3   4\n3   4   5\n4   +5\n".as_bytes()).collect();
+    assert!(matches!(
+        errors[1],
+        Err(LocationListError::MissingIds { line: 2, .. })
+    ));
+    assert!(matches!(
+        errors[2],
+        Err(LocationListError::MissingIds { line: 3, .. })
+    ));
+
+    let mut pairs = LocationPairs::new(&b"3   4\n\xff   4\n5   6\n"[..]);
+    assert_eq!(pairs.next().unwrap().unwrap(), (3, 4));
+    assert!(matches!(
+        pairs.next(),
+        Some(Err(LocationListError::Io { line: 2, .. }))
+    ));
+    assert!(pairs.next().is_none());
+    assert!(day_01::puzzle02_from_reader(&b"3   4\n\xff   4\n"[..]).is_err());
+}
+
+#[test]
+fn test_similarity_counter() {
+    let input = generator::generate(3, 1000);
+    let location_lists: LocationLists = input.parse().unwrap();
+    assert_eq!(
+        day_01::puzzle02_from_reader(input.as_bytes()).unwrap(),
+        location_lists.similarity_score().unwrap()
+    );
+
+    let mut counter = SimilarityCounter::default();
+    counter.add(-2, 7).unwrap();
+    counter.add(7, -2).unwrap();
+    counter.add(7, 1).unwrap();
+    assert_eq!(counter.score().unwrap(), -2 + 7 + 7);
+
+    let mut counter = SimilarityCounter::default();
+    counter.add(i64::MAX, i64::MAX).unwrap();
+    counter.add(i64::MAX, 1).unwrap();
+    assert!(counter.score().is_err());
+}
+
+#[test]
+fn test_extreme_ids() {
+    use aoc_core::{Part, Solution};
+    use day_01::{puzzle01_from_str, puzzle02_from_reader, puzzle02_from_str, Day01};
+
+    // the distance of the ids does not fit into an i64
+    let input = "9223372036854775807   -9223372036854775808\n";
+    assert!(puzzle01_from_str(input).is_err());
+    let location_lists: LocationLists = input.parse().unwrap();
+    let reconciliation = location_lists.reconcile();
+    assert_eq!(reconciliation.pairs[0].distance, u64::MAX);
+    assert_eq!(reconciliation.total_distance(), u128::from(u64::MAX));
+
+    // the largest id occurs twice in the right list
+    let input = "9223372036854775807   9223372036854775807\n1   9223372036854775807\n";
+    let location_lists: LocationLists = input.parse().unwrap();
+    assert!(location_lists.similarity_score().is_err());
+    assert!(puzzle02_from_str(input).is_err());
+    assert!(puzzle02_from_reader(input.as_bytes()).is_err());
+    assert!(Day01::solve(&location_lists, Part::Two, &Default::default()).is_err());
+
+    // large products that cancel out
+    let input =
+        "9223372036854775807   9223372036854775807\n-9223372036854775807   -9223372036854775807\n";
+    assert_eq!(puzzle02_from_str(input).unwrap(), 0);
+    assert_eq!(puzzle02_from_reader(input.as_bytes()).unwrap(), 0);
+}
@@ -0,0 +1,95 @@
+use day_01::{puzzle02_from_reader, LocationLists, Metric, Pairing};
+use proptest::prelude::*;
+
+fn oracle_total_distance(pairs: &[(i64, i64)]) -> i64 {
+    let mut left: Vec<_> = pairs.iter().map(|(left, _)| *left).collect();
+    let mut right: Vec<_> = pairs.iter().map(|(_, right)| *right).collect();
+    left.sort();
+    right.sort();
+    left.iter()
+        .zip(&right)
+        .map(|(left, right)| (left - right).abs())
+        .sum()
+}
+
+fn oracle_similarity_score(pairs: &[(i64, i64)]) -> i64 {
+    pairs
+        .iter()
+        .map(|(left, _)| {
+            let count = pairs.iter().filter(|(_, right)| right == left).count();
+            left * count as i64
+        })
+        .sum()
+}
+
+// the minimum total distance over all pairings, trying every permutation of the right list
+fn oracle_optimal_distance(metric: Metric, left: &[i64], right: &mut Vec<i64>, k: usize) -> f64 {
+    if k == right.len() {
+        return left
+            .iter()
+            .zip(right.iter())
+            .map(|(left, right)| metric.distance(*left, *right).as_f64())
+            .sum();
+    }
+    let mut minimum = f64::INFINITY;
+    for i in k..right.len() {
+        right.swap(k, i);
+        minimum = minimum.min(oracle_optimal_distance(metric, left, right, k + 1));
+        right.swap(k, i);
+    }
+    minimum
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_oracle(pairs in prop::collection::vec((0..20i64, 0..20i64), 1..30)) {
+        let input: String = pairs
+            .iter()
+            .map(|(left, right)| format!("{}   {}\n", left, right))
+            .collect();
+        let location_lists: LocationLists = input.parse().unwrap();
+        prop_assert_eq!(location_lists.total_distance().unwrap(), oracle_total_distance(&pairs));
+        prop_assert_eq!(
+            location_lists.similarity_score().unwrap(),
+            oracle_similarity_score(&pairs)
+        );
+    }
+
+    #[test]
+    fn test_optimal_pairing_oracle(
+        pairs in prop::collection::vec((-30..30i64, -30..30i64), 1..7),
+        metric in prop::sample::select(Metric::ALL.to_vec())
+    ) {
+        let location_lists = LocationLists {
+            left: pairs.iter().map(|(left, _)| *left).collect(),
+            right: pairs.iter().map(|(_, right)| *right).collect(),
+        };
+        let total_distance = |pairing| {
+            location_lists
+                .total_distance_by(metric, pairing)
+                .unwrap()
+                .as_f64()
+        };
+        let optimal = total_distance(Pairing::Optimal);
+        let mut right = location_lists.right.clone();
+        let expected = oracle_optimal_distance(metric, &location_lists.left, &mut right, 0);
+        prop_assert!((optimal - expected).abs() < 1e-9, "{} != {}", optimal, expected);
+        prop_assert!(optimal <= total_distance(Pairing::Sorted) + 1e-9);
+    }
+
+    #[test]
+    fn test_streaming_similarity_oracle(
+        pairs in prop::collection::vec((-20..20i64, -20..20i64), 1..30)
+    ) {
+        let input: String = pairs
+            .iter()
+            .map(|(left, right)| format!("{}   {}\n", left, right))
+            .collect();
+        prop_assert_eq!(
+            puzzle02_from_reader(input.as_bytes()).unwrap(),
+            oracle_similarity_score(&pairs)
+        );
+    }
+}
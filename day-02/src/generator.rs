@@ -0,0 +1,36 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` reports of 5 to 8 levels, about half of them gradually increasing or decreasing
+/// and the others with one level replaced by a random one.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut input = String::new();
+    for _ in 0..size {
+        let length = rng.gen_range(5..=8);
+        let increasing = rng.gen_bool(0.5);
+        let mut level: u32 = if increasing {
+            rng.gen_range(1..=50)
+        } else {
+            rng.gen_range(50..=99)
+        };
+        let mut levels = Vec::with_capacity(length);
+        for _ in 0..length {
+            levels.push(level);
+            let step = rng.gen_range(1..=3);
+            level = if increasing {
+                level + step
+            } else {
+                level - step
+            };
+        }
+        if rng.gen_bool(0.5) {
+            let index = rng.gen_range(0..length);
+            levels[index] = rng.gen_range(1..=99);
+        }
+        let report: Vec<_> = levels.iter().map(u32::to_string).collect();
+        input.push_str(&report.join(" "));
+        input.push('\n');
+    }
+    input
+}
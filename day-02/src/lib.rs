@@ -0,0 +1,296 @@
+pub mod generator;
+pub mod validator;
+
+use anyhow::Context;
+use aoc_core::{Answer, Location, Problem, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    fs::File,
+    io::{self, Read},
+    num::ParseIntError,
+    path::Path,
+    slice::Iter,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ReportError {
+    #[error("report on line {line} has no levels")]
+    EmptyReport { line: usize },
+    #[error("invalid level {text:?} at {location}")]
+    InvalidLevel {
+        location: Location,
+        text: String,
+        source: ParseIntError,
+    },
+}
+
+const MAX_STEP_SIZE: i64 = 3;
+
+// `previous` is the level before `number`, the dampener may drop the level on either side of a
+// bad step
+fn check_ascending(
+    previous: Option<i64>,
+    number: i64,
+    mut number_iter: Iter<i64>,
+    problem_dampener: bool,
+) -> bool {
+    let is_step = |from: i64, to: i64| {
+        // a difference beyond the i64 range is no step either
+        to.checked_sub(from)
+            .is_some_and(|diff| 0 < diff && diff <= MAX_STEP_SIZE)
+    };
+    if let Some(next_number) = number_iter.next() {
+        if is_step(number, *next_number) {
+            check_ascending(Some(number), *next_number, number_iter, problem_dampener)
+        } else if problem_dampener {
+            check_ascending(previous, number, number_iter.clone(), false)
+                || previous.is_some_and(|previous| {
+                    is_step(previous, *next_number)
+                        && check_ascending(Some(previous), *next_number, number_iter, false)
+                })
+        } else {
+            false
+        }
+    } else {
+        true
+    }
+}
+
+fn check_descending(
+    previous: Option<i64>,
+    number: i64,
+    mut number_iter: Iter<i64>,
+    problem_dampener: bool,
+) -> bool {
+    let is_step = |from: i64, to: i64| {
+        from.checked_sub(to)
+            .is_some_and(|diff| 0 < diff && diff <= MAX_STEP_SIZE)
+    };
+    if let Some(next_number) = number_iter.next() {
+        if is_step(number, *next_number) {
+            check_descending(Some(number), *next_number, number_iter, problem_dampener)
+        } else if problem_dampener {
+            check_descending(previous, number, number_iter.clone(), false)
+                || previous.is_some_and(|previous| {
+                    is_step(previous, *next_number)
+                        && check_descending(Some(previous), *next_number, number_iter, false)
+                })
+        } else {
+            false
+        }
+    } else {
+        true
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i64>>;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_reports(input)?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(reports: &Self::Input, _: &()) -> anyhow::Result<Answer> {
+        Ok(count_safe_reports(reports, is_safe)?.into())
+    }
+
+    fn part2(reports: &Self::Input, _: &()) -> anyhow::Result<Answer> {
+        Ok(count_safe_reports(reports, is_safe_with_dampener)?.into())
+    }
+}
+
+/// Parses one report of whitespace separated levels per line.
+pub fn parse_reports(input: &str) -> Result<Vec<Vec<i64>>, ReportError> {
+    let mut reports = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let numbers_result: Result<Vec<_>, _> = line
+            .split_whitespace()
+            .map(|n| {
+                n.parse::<i64>()
+                    .map_err(|source| ReportError::InvalidLevel {
+                        location: Location::of(input, n),
+                        text: n.to_string(),
+                        source,
+                    })
+            })
+            .collect();
+        let numbers = numbers_result?;
+        if numbers.is_empty() {
+            return Err(ReportError::EmptyReport { line: index + 1 });
+        }
+        reports.push(numbers);
+    }
+    Ok(reports)
+}
+
+/// Whether the levels are strictly ascending or descending by at most 3 per step.
+pub fn is_safe(numbers: &[i64]) -> anyhow::Result<bool> {
+    let mut number_iter = numbers.iter();
+    let first_number: i64 = *number_iter.next().context("no first value")?;
+    if let Some(second_number) = number_iter.next() {
+        // a difference beyond the i64 range is too large a step
+        let diff = second_number.checked_sub(first_number).unwrap_or(i64::MAX);
+        if (-MAX_STEP_SIZE..0).contains(&diff) {
+            Ok(check_descending(
+                Some(first_number),
+                *second_number,
+                number_iter,
+                false,
+            ))
+        } else if (1..=MAX_STEP_SIZE).contains(&diff) {
+            Ok(check_ascending(
+                Some(first_number),
+                *second_number,
+                number_iter,
+                false,
+            ))
+        } else {
+            Ok(false)
+        }
+    } else {
+        Ok(true)
+    }
+}
+
+/// Whether the report is safe when the problem dampener may drop a single bad level.
+pub fn is_safe_with_dampener(numbers: &[i64]) -> anyhow::Result<bool> {
+    let mut number_iter = numbers.iter();
+    let first_number = number_iter.next().context("no first value")?;
+    if check_ascending(None, *first_number, number_iter.clone(), true)
+        || check_descending(None, *first_number, number_iter.clone(), true)
+    {
+        Ok(true)
+    } else {
+        // drop the first level
+        let second_number = number_iter.next().context("no second value")?;
+        Ok(
+            check_ascending(None, *second_number, number_iter.clone(), false)
+                || check_descending(None, *second_number, number_iter.clone(), false),
+        )
+    }
+}
+
+/// The number of reports accepted by `is_safe_report`, checked concurrently with the `parallel`
+/// feature.
+pub fn count_safe_reports<F>(reports: &[Vec<i64>], is_safe_report: F) -> anyhow::Result<i64>
+where
+    F: Fn(&[i64]) -> anyhow::Result<bool> + Sync,
+{
+    #[cfg(feature = "parallel")]
+    let reports = reports.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let reports = reports.iter();
+    reports
+        .map(|report| is_safe_report(report).map(i64::from))
+        .sum()
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<i64> {
+    let reports = parse_reports(input)?;
+    count_safe_reports(&reports, is_safe)
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<i64> {
+    let reports = parse_reports(input)?;
+    count_safe_reports(&reports, is_safe_with_dampener)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 2)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 2)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1").unwrap(), 4)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use crate::{parse_reports, ReportError};
+        use aoc_core::Location;
+
+        let error = parse_reports("1 2 3\n4 5 b6\n").unwrap_err();
+        assert!(matches!(
+            error,
+            ReportError::InvalidLevel { location, .. } if location == Location::new(2, 5)
+        ));
+        let error = parse_reports("1 2 3\n\n").unwrap_err();
+        assert!(matches!(error, ReportError::EmptyReport { line: 2 }));
+    }
+
+    #[test]
+    fn test_dampener_drops_level_before_bad_step() {
+        use crate::is_safe_with_dampener;
+
+        assert!(is_safe_with_dampener(&[3, 6, 4, 5]).unwrap());
+        assert!(is_safe_with_dampener(&[9, 5, 6, 3]).unwrap());
+        assert!(!is_safe_with_dampener(&[1, 5, 9]).unwrap());
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("7 6 4\n\n1 x 3\n"),
+            [
+                Problem::at_line(2, "the report has no levels"),
+                Problem::at_line(3, "invalid level \"x\"")
+            ]
+        );
+    }
+}
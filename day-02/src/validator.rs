@@ -0,0 +1,13 @@
+use aoc_core::{check_numbers, Problem};
+
+/// Every line is a report of at least one level.
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            problems.push(Problem::at_line(index + 1, "the report has no levels"));
+        }
+        problems.extend(check_numbers::<i64>(index + 1, line, "level"));
+    }
+    problems
+}
@@ -0,0 +1,14 @@
+use day_02::{count_safe_reports, is_safe, is_safe_with_dampener, parse_reports};
+
+#[test]
+fn test_reports() {
+    let reports = parse_reports(include_str!("../data/test_data_1")).unwrap();
+    assert_eq!(reports.len(), 6);
+    assert!(is_safe(&[7, 6, 4, 2, 1]).unwrap());
+    assert!(!is_safe(&[1, 3, 2, 4, 5]).unwrap());
+    assert!(is_safe_with_dampener(&[1, 3, 2, 4, 5]).unwrap());
+    assert_eq!(
+        count_safe_reports(&reports, is_safe_with_dampener).unwrap(),
+        4
+    );
+}
@@ -0,0 +1,64 @@
+use day_02::{is_safe, is_safe_with_dampener};
+use proptest::prelude::*;
+
+// all steps increase or all steps decrease by 1 to 3
+fn oracle_is_safe(levels: &[i64]) -> bool {
+    let steps: Vec<_> = levels
+        .windows(2)
+        .map(|pair| i128::from(pair[1]) - i128::from(pair[0]))
+        .collect();
+    steps.iter().all(|step| (1..=3).contains(step))
+        || steps.iter().all(|step| (-3..=-1).contains(step))
+}
+
+// safe as is or with any single level removed
+fn oracle_is_safe_with_dampener(levels: &[i64]) -> bool {
+    oracle_is_safe(levels)
+        || (0..levels.len()).any(|index| {
+            let mut dampened = levels.to_vec();
+            dampened.remove(index);
+            oracle_is_safe(&dampened)
+        })
+}
+
+#[test]
+fn test_dampener_drops_earlier_level() {
+    assert!(is_safe_with_dampener(&[3, 6, 4, 5]).unwrap());
+    assert!(is_safe_with_dampener(&[5, 2, 4, 3]).unwrap());
+    assert!(!is_safe_with_dampener(&[3, 6, 4, 4]).unwrap());
+}
+
+#[test]
+fn test_extreme_levels() {
+    assert!(!is_safe(&[i64::MIN, i64::MAX]).unwrap());
+    assert!(!is_safe(&[i64::MAX, i64::MIN]).unwrap());
+    assert!(is_safe_with_dampener(&[i64::MIN, i64::MIN + 2, i64::MAX, i64::MIN + 3]).unwrap());
+    assert!(is_safe(&[i64::MAX, i64::MAX - 3]).unwrap());
+}
+
+// small levels, or levels close to i64::MIN or i64::MAX with a few far away ones
+fn levels() -> impl Strategy<Value = Vec<i64>> {
+    let extreme = prop_oneof![Just(i64::MIN), Just(i64::MAX - 12)];
+    prop_oneof![
+        prop::collection::vec(0..10i64, 1..8),
+        (extreme, prop::collection::vec(0..13i64, 1..8))
+            .prop_map(|(base, offsets)| { offsets.iter().map(|offset| base + offset).collect() }),
+        prop::collection::vec(
+            prop_oneof![Just(i64::MIN), Just(i64::MAX), i64::MIN..i64::MIN + 4],
+            1..6
+        ),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_oracle(levels in levels()) {
+        prop_assert_eq!(is_safe(&levels).unwrap(), oracle_is_safe(&levels));
+        prop_assert_eq!(
+            is_safe_with_dampener(&levels).unwrap(),
+            oracle_is_safe_with_dampener(&levels)
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_03::{puzzle01, puzzle02};
+
+fn bench_puzzles(c: &mut Criterion) {
+    c.bench_function("day 03 part 1", |b| {
+        b.iter(|| puzzle01("data/data_1").unwrap())
+    });
+    c.bench_function("day 03 part 2", |b| {
+        b.iter(|| puzzle02("data/data_1").unwrap())
+    });
+}
+
+criterion_group!(benches, bench_puzzles);
+criterion_main!(benches);
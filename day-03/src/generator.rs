@@ -0,0 +1,60 @@
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// fragments that resemble instructions without being valid ones
+const NOISE: &[&str] = &[
+    "mul[3,7]",
+    "mul(4*",
+    "mul ( 2 , 4 )",
+    "mul(32,64]",
+    "mul(1234,5)",
+    "?(12,34)",
+    "select()",
+    "from()",
+    "what()",
+    "who()",
+    "how()",
+    "when()",
+    "where()",
+    "don't",
+    "do",
+    "'",
+    "%",
+    "&",
+    "!",
+    "@",
+    "^",
+    "+",
+    "<",
+    ">",
+    "#",
+    "$",
+    "[",
+    "]",
+    ")",
+    "(",
+    ",",
+    " ",
+];
+
+/// One line of corrupted memory with `size` valid `mul(X,Y)` instructions between noise and
+/// occasional `do()` and `don't()` instructions.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut memory = String::new();
+    for _ in 0..size {
+        for _ in 0..rng.gen_range(0..4) {
+            memory.push_str(NOISE.choose(&mut rng).unwrap_or(&""));
+        }
+        match rng.gen_range(0..10) {
+            0 => memory.push_str("do()"),
+            1 => memory.push_str("don't()"),
+            _ => {}
+        }
+        let x: u32 = rng.gen_range(1..1000);
+        let y: u32 = rng.gen_range(1..1000);
+        memory.push_str(&format!("mul({},{})", x, y));
+    }
+    memory.push('\n');
+    memory
+}
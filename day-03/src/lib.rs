@@ -0,0 +1,164 @@
+pub mod generator;
+pub mod validator;
+
+use aoc_core::{Answer, Location, Problem, Solution};
+use regex::Regex;
+use std::{
+    fs::File,
+    io::{self, Read},
+    num::ParseIntError,
+    path::Path,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum InstructionError {
+    #[error("invalid operand {text:?} of a mul instruction at {location}")]
+    InvalidOperand {
+        location: Location,
+        text: String,
+        source: ParseIntError,
+    },
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = String;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(memory: &Self::Input, _: &()) -> anyhow::Result<Answer> {
+        Ok(sum_of_multiplications(memory)?.into())
+    }
+
+    fn part2(memory: &Self::Input, _: &()) -> anyhow::Result<Answer> {
+        Ok(sum_of_enabled_multiplications(memory)?.into())
+    }
+}
+
+fn parse_operand(memory: &str, operand: &str) -> Result<i64, InstructionError> {
+    operand
+        .parse::<i64>()
+        .map_err(|source| InstructionError::InvalidOperand {
+            location: Location::of(memory, operand),
+            text: operand.to_string(),
+            source,
+        })
+}
+
+/// The sum of the products of all valid `mul(X,Y)` instructions.
+pub fn sum_of_multiplications(memory: &str) -> anyhow::Result<i64> {
+    let line_regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)")?;
+
+    let mut sum = 0;
+    for captures in line_regex.captures_iter(memory) {
+        sum += parse_operand(memory, &captures[1])? * parse_operand(memory, &captures[2])?;
+    }
+    Ok(sum)
+}
+
+/// Like [`sum_of_multiplications`], skipping the instructions after a `don't()` until the next
+/// `do()`. The memory is one sequence of instructions, even across lines.
+pub fn sum_of_enabled_multiplications(memory: &str) -> anyhow::Result<i64> {
+    let instruction_regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)")?;
+
+    let mut enabled = true;
+    let mut sum = 0;
+    for captures in instruction_regex.captures_iter(memory) {
+        match &captures[0] {
+            "do()" => enabled = true,
+            "don't()" => enabled = false,
+            _ if enabled => {
+                sum += parse_operand(memory, &captures[1])? * parse_operand(memory, &captures[2])?
+            }
+            _ => {}
+        }
+    }
+    Ok(sum)
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<i64> {
+    sum_of_multiplications(&Day03::parse(input)?)
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<i64> {
+    sum_of_enabled_multiplications(&Day03::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 161)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 161)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_2").unwrap(), 48)
+    }
+
+    #[test]
+    fn test_disabled_across_lines() {
+        use crate::sum_of_enabled_multiplications;
+
+        let memory = "mul(1,1)don't()\nmul(2,2)do()mul(3,3)";
+        assert_eq!(sum_of_enabled_multiplications(memory).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(validate("\n"), [Problem::new("the memory is empty")]);
+    }
+}
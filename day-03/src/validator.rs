@@ -0,0 +1,10 @@
+use aoc_core::Problem;
+
+/// Any corrupted memory is valid, as long as there is some.
+pub fn validate(input: &str) -> Vec<Problem> {
+    if input.trim().is_empty() {
+        vec![Problem::new("the memory is empty")]
+    } else {
+        Vec::new()
+    }
+}
@@ -0,0 +1,12 @@
+use day_03::{sum_of_enabled_multiplications, sum_of_multiplications};
+
+#[test]
+fn test_multiplications() {
+    let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    assert_eq!(
+        sum_of_multiplications("mul(44,46)mul(4*mul(123,4)").unwrap(),
+        2516
+    );
+    assert_eq!(sum_of_multiplications(memory).unwrap(), 161);
+    assert_eq!(sum_of_enabled_multiplications(memory).unwrap(), 48);
+}
@@ -0,0 +1,86 @@
+use day_03::{sum_of_enabled_multiplications, sum_of_multiplications};
+use proptest::prelude::*;
+
+// a number of 1 to 3 digits and the text after it
+fn number(text: &str) -> Option<(i64, &str)> {
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+    if (1..=3).contains(&digits) {
+        Some((text[..digits].parse().ok()?, &text[digits..]))
+    } else {
+        None
+    }
+}
+
+fn mul_instruction(text: &str) -> Option<(i64, i64)> {
+    let (x, text) = number(text.strip_prefix("mul(")?)?;
+    let (y, text) = number(text.strip_prefix(',')?)?;
+    text.strip_prefix(')')?;
+    Some((x, y))
+}
+
+// scans the memory character by character, `do()` and `don't()` only count with `conditionals`
+fn oracle_sum(memory: &str, conditionals: bool) -> i64 {
+    let mut enabled = true;
+    let mut sum = 0;
+    for index in 0..memory.len() {
+        let text = &memory[index..];
+        if text.starts_with("do()") {
+            enabled = true;
+        } else if text.starts_with("don't()") {
+            enabled = false;
+        } else if let Some((x, y)) = mul_instruction(text) {
+            if enabled || !conditionals {
+                sum += x * y;
+            }
+        }
+    }
+    sum
+}
+
+const FRAGMENTS: &[&str] = &[
+    "mul(2,3)",
+    "mul(11,8)",
+    "mul(123,4)",
+    "mul(1234,5)",
+    "mul(4*",
+    "mul(",
+    "mu",
+    "l(7,7)",
+    "do()",
+    "don't()",
+    "do",
+    "n't()",
+    "(",
+    ")",
+    ",",
+    "1",
+    "x",
+    " ",
+    "\n",
+];
+
+#[test]
+fn test_disabled_across_lines() {
+    assert_eq!(
+        sum_of_enabled_multiplications("don't()\nmul(2,3)").unwrap(),
+        0
+    );
+    assert_eq!(
+        sum_of_enabled_multiplications("mudon't()do()l(2,3)").unwrap(),
+        0
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+
+    #[test]
+    fn test_oracle(fragments in prop::collection::vec(prop::sample::select(FRAGMENTS), 0..40)) {
+        let memory = fragments.concat();
+        prop_assert_eq!(sum_of_multiplications(&memory).unwrap(), oracle_sum(&memory, false));
+        prop_assert_eq!(
+            sum_of_enabled_multiplications(&memory).unwrap(),
+            oracle_sum(&memory, true)
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A `size` x `size` word search of the letters `X`, `M`, `A` and `S`.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(*['X', 'M', 'A', 'S'].choose(&mut rng).unwrap_or(&'X'));
+        }
+        input.push('\n');
+    }
+    input
+}
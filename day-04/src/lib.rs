@@ -0,0 +1,291 @@
+pub mod generator;
+pub mod validator;
+
+use aoc_core::{Answer, Problem, Solution};
+use grid::{Grid, GridError, Offset, Point};
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+    str::FromStr,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum XmasError {
+    #[error("Could not parse char {0:?} to XMAS struct")]
+    XmasParseFailed(char),
+    #[error("Could not parse the word search")]
+    InvalidGrid(#[from] GridError),
+    #[error("Can not search an empty line")]
+    EmptyLine,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone)]
+pub enum XMAS {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[allow(clippy::enum_variant_names)]
+enum NextValue {
+    IsValid,
+    IsXmas,
+    IsNotValid,
+}
+
+fn is_mas(
+    center: &XMAS,
+    upper_left: &XMAS,
+    upper_right: &XMAS,
+    lower_left: &XMAS,
+    lower_right: &XMAS,
+) -> bool {
+    match center {
+        XMAS::A => match (upper_left, lower_right) {
+            (XMAS::M, XMAS::S) | (XMAS::S, XMAS::M) => matches!(
+                (upper_right, lower_left),
+                (XMAS::M, XMAS::S) | (XMAS::S, XMAS::M)
+            ),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+impl XMAS {
+    fn check_next(&self, next: &XMAS) -> NextValue {
+        match self {
+            XMAS::X => match next {
+                XMAS::M => NextValue::IsValid,
+                _ => NextValue::IsNotValid,
+            },
+            XMAS::M => match next {
+                XMAS::A => NextValue::IsValid,
+                _ => NextValue::IsNotValid,
+            },
+            XMAS::A => match next {
+                XMAS::S => NextValue::IsXmas,
+                _ => NextValue::IsNotValid,
+            },
+            XMAS::S => NextValue::IsNotValid,
+        }
+    }
+}
+
+impl TryFrom<char> for XMAS {
+    type Error = XmasError;
+    fn try_from(value: char) -> Result<XMAS, XmasError> {
+        match value {
+            'X' => Ok(XMAS::X),
+            'M' => Ok(XMAS::M),
+            'A' => Ok(XMAS::A),
+            'S' => Ok(XMAS::S),
+            _ => Err(XmasError::XmasParseFailed(value)),
+        }
+    }
+}
+
+fn xmas_count<'a, T>(v_iter: &mut T) -> Result<i64, XmasError>
+where
+    T: Iterator<Item = &'a XMAS>,
+{
+    let Some(mut start) = v_iter.next() else {
+        return Err(XmasError::EmptyLine);
+    };
+    let mut counter = 0;
+    let mut count = 0;
+
+    for next in v_iter {
+        match start.check_next(next) {
+            NextValue::IsValid => count += 1,
+            NextValue::IsNotValid => count = 0,
+            NextValue::IsXmas => {
+                if count == 2 {
+                    counter += 1;
+                }
+                count = 0
+            }
+        }
+        start = next;
+    }
+
+    Ok(counter)
+}
+
+/// The letter grid of the word search.
+#[derive(Debug, Clone)]
+pub struct WordSearch {
+    grid: Grid<XMAS>,
+}
+
+impl FromStr for WordSearch {
+    type Err = XmasError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(s, |char| XMAS::try_from(char).ok())?;
+        Ok(WordSearch { grid })
+    }
+}
+
+impl WordSearch {
+    pub fn grid(&self) -> &Grid<XMAS> {
+        &self.grid
+    }
+
+    // reading every row, column and diagonal in both directions
+    pub fn count_xmas(&self) -> Result<i64, XmasError> {
+        let mut counter = 0;
+        for offset in Offset::NEIGHBOURS_8 {
+            for mut line in self.grid.lines(offset) {
+                counter += xmas_count(&mut line)?;
+            }
+        }
+
+        Ok(counter)
+    }
+
+    pub fn count_x_mas(&self) -> Result<i64, XmasError> {
+        let grid = &self.grid;
+        let corner = |point: Point, offset: Offset| {
+            point
+                .checked_add(offset)
+                .and_then(|corner| grid.get(corner))
+        };
+
+        let mut counter = 0;
+        for (point, center) in grid.iter() {
+            if let (Some(upper_left), Some(upper_right), Some(lower_left), Some(lower_right)) = (
+                corner(point, Offset::UP_LEFT),
+                corner(point, Offset::UP_RIGHT),
+                corner(point, Offset::DOWN_LEFT),
+                corner(point, Offset::DOWN_RIGHT),
+            ) {
+                if is_mas(center, upper_left, upper_right, lower_left, lower_right) {
+                    counter += 1;
+                }
+            }
+        }
+
+        Ok(counter)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = WordSearch;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(word_search: &WordSearch, _: &()) -> anyhow::Result<Answer> {
+        Ok(word_search.count_xmas()?.into())
+    }
+
+    fn part2(word_search: &WordSearch, _: &()) -> anyhow::Result<Answer> {
+        Ok(word_search.count_x_mas()?.into())
+    }
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<i64> {
+    let word_search: WordSearch = input.parse()?;
+    Ok(word_search.count_xmas()?)
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<i64> {
+    let word_search: WordSearch = input.parse()?;
+    Ok(word_search.count_x_mas()?)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 18)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 18)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1").unwrap(), 9)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use crate::{WordSearch, XmasError};
+        use grid::GridError;
+
+        assert_eq!(
+            "XMAS\nXMAZ\n".parse::<WordSearch>().unwrap_err(),
+            XmasError::InvalidGrid(GridError::InvalidChar {
+                line: 2,
+                column: 4,
+                char: 'Z'
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("XMAS\nXMA\nXMAB\n"),
+            [
+                Problem::at_line(2, "row has 3 positions, the first row 4"),
+                Problem::at_line(3, "invalid character 'B' in column 4")
+            ]
+        );
+    }
+}
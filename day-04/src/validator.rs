@@ -0,0 +1,6 @@
+use aoc_core::{check_grid, Problem};
+
+/// A rectangular grid of the letters `X`, `M`, `A` and `S`.
+pub fn validate(input: &str) -> Vec<Problem> {
+    check_grid(input, |char| matches!(char, 'X' | 'M' | 'A' | 'S'))
+}
@@ -0,0 +1,11 @@
+use day_04::{WordSearch, XMAS};
+use grid::Point;
+
+#[test]
+fn test_word_search() {
+    let word_search: WordSearch = include_str!("../data/test_data_1").parse().unwrap();
+    assert_eq!(word_search.grid().width(), 10);
+    assert!(matches!(word_search.grid()[Point::new(4, 0)], XMAS::X));
+    assert_eq!(word_search.count_xmas().unwrap(), 18);
+    assert_eq!(word_search.count_x_mas().unwrap(), 9);
+}
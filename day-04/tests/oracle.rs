@@ -0,0 +1,78 @@
+use day_04::WordSearch;
+use proptest::prelude::*;
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+fn letter(rows: &[Vec<char>], x: isize, y: isize) -> Option<char> {
+    let row = rows.get(usize::try_from(y).ok()?)?;
+    row.get(usize::try_from(x).ok()?).copied()
+}
+
+// reads `XMAS` from every position in every direction
+fn oracle_count_xmas(rows: &[Vec<char>]) -> i64 {
+    let mut count = 0;
+    for y in 0..rows.len() as isize {
+        for x in 0..rows[0].len() as isize {
+            for (dx, dy) in DIRECTIONS {
+                let word: Option<String> = (0..4)
+                    .map(|step| letter(rows, x + step * dx, y + step * dy))
+                    .collect();
+                if word.as_deref() == Some("XMAS") {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+// `MAS` forwards or backwards on both diagonals through every `A`
+fn oracle_count_x_mas(rows: &[Vec<char>]) -> i64 {
+    let mut count = 0;
+    for y in 0..rows.len() as isize {
+        for x in 0..rows[0].len() as isize {
+            let diagonal = |dx: isize| -> Option<String> {
+                [(-dx, -1), (0, 0), (dx, 1)]
+                    .iter()
+                    .map(|(offset_x, offset_y)| letter(rows, x + offset_x, y + offset_y))
+                    .collect()
+            };
+            let is_mas = |word: Option<String>| matches!(word.as_deref(), Some("MAS" | "SAM"));
+            if is_mas(diagonal(1)) && is_mas(diagonal(-1)) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn word_search() -> impl Strategy<Value = Vec<Vec<char>>> {
+    (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+        let letter = prop::sample::select(vec!['X', 'M', 'A', 'S']);
+        prop::collection::vec(prop::collection::vec(letter, width), height)
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_oracle(rows in word_search()) {
+        let input: String = rows
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+        let word_search: WordSearch = input.parse().unwrap();
+        prop_assert_eq!(word_search.count_xmas().unwrap(), oracle_count_xmas(&rows));
+        prop_assert_eq!(word_search.count_x_mas().unwrap(), oracle_count_x_mas(&rows));
+    }
+}
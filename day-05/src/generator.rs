@@ -0,0 +1,35 @@
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The number of distinct pages, the rules order every pair of them.
+const PAGES: usize = 25;
+
+/// The rules of a random total order of two digit pages followed by a blank line and `size`
+/// updates of an odd number of pages, about half of them correctly ordered.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut pages: Vec<u32> = (10..100).collect();
+    pages.shuffle(&mut rng);
+    pages.truncate(PAGES);
+
+    let mut rules = Vec::new();
+    for (index, before) in pages.iter().enumerate() {
+        for after in &pages[index + 1..] {
+            rules.push(format!("{}|{}", before, after));
+        }
+    }
+    rules.shuffle(&mut rng);
+
+    let mut updates = Vec::with_capacity(size);
+    for _ in 0..size {
+        let length = 2 * rng.gen_range(1..PAGES / 2) + 1;
+        let mut update: Vec<_> = pages.choose_multiple(&mut rng, length).collect();
+        if rng.gen_bool(0.5) {
+            update.sort_by_key(|page| pages.iter().position(|other| other == *page));
+        }
+        let update: Vec<_> = update.iter().map(|page| page.to_string()).collect();
+        updates.push(update.join(","));
+    }
+
+    format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n"))
+}
@@ -0,0 +1,395 @@
+pub mod generator;
+pub mod validator;
+
+use aoc_core::{Answer, Location, Problem, Solution};
+use regex::Regex;
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{self, Read},
+    num::ParseIntError,
+    path::Path,
+    str::FromStr,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PrintQueueError {
+    #[error("no blank line between rules and updates")]
+    MissingSeparator,
+    #[error("line {line} is no page ordering rule `X|Y`: {text:?}")]
+    InvalidRule { line: usize, text: String },
+    #[error("update on line {line} has no pages")]
+    EmptyUpdate { line: usize },
+    #[error("invalid page number {text:?} at {location}")]
+    InvalidPage {
+        location: Location,
+        text: String,
+        source: ParseIntError,
+    },
+    #[error("the rules order the pages {pages:?} of update {update} in a cycle")]
+    CyclicRules { update: usize, pages: Vec<u16> },
+}
+
+/// A cycle of the rules among the pages of an update, from the first page back to it, e.g.
+/// `[47, 53, 47]` for the rules `47|53` and `53|47`. No order of the pages satisfies the rules.
+/// `invalid_successors` maps a page to the pages ordered before it.
+pub(crate) fn find_cycle(
+    invalid_successors: &HashMap<u16, Vec<u16>>,
+    pages: &[u16],
+) -> Option<Vec<u16>> {
+    let update_pages: HashSet<u16> = pages.iter().copied().collect();
+    let mut finished = HashSet::new();
+    let mut path = Vec::new();
+    for page in pages {
+        if let Some(mut cycle) = visit(
+            *page,
+            invalid_successors,
+            &update_pages,
+            &mut finished,
+            &mut path,
+        ) {
+            // the edges lead to the pages before, the rules the other way round
+            cycle.reverse();
+            return Some(cycle);
+        }
+    }
+    None
+}
+
+// depth first search from `page` for a page on `path`, only through the pages of the update
+fn visit(
+    page: u16,
+    invalid_successors: &HashMap<u16, Vec<u16>>,
+    update_pages: &HashSet<u16>,
+    finished: &mut HashSet<u16>,
+    path: &mut Vec<u16>,
+) -> Option<Vec<u16>> {
+    if finished.contains(&page) {
+        return None;
+    }
+    if let Some(start) = path.iter().position(|visited| *visited == page) {
+        let mut cycle = path[start..].to_vec();
+        cycle.push(page);
+        return Some(cycle);
+    }
+    path.push(page);
+    for before in invalid_successors.get(&page).into_iter().flatten() {
+        if update_pages.contains(before) {
+            if let Some(cycle) = visit(*before, invalid_successors, update_pages, finished, path) {
+                return Some(cycle);
+            }
+        }
+    }
+    path.pop();
+    finished.insert(page);
+    None
+}
+
+/// The page ordering rules and the updates of the print queue.
+#[derive(Debug, Clone)]
+pub struct PrintQueue {
+    // the pages that must not follow a page, i.e. the pages ordered before it by a rule
+    invalid_successors: HashMap<u16, Vec<u16>>,
+    updates: Vec<Vec<u16>>,
+}
+
+impl FromStr for PrintQueue {
+    type Err = PrintQueueError;
+
+    // the rules and the updates are separated by a blank line
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rules, update_lines) = s
+            .split_once("\n\n")
+            .ok_or(PrintQueueError::MissingSeparator)?;
+        let parse_page = |page: &str| {
+            page.parse::<u16>()
+                .map_err(|source| PrintQueueError::InvalidPage {
+                    location: Location::of(s, page),
+                    text: page.to_string(),
+                    source,
+                })
+        };
+
+        let line_regex = Regex::new(r"^(\d+)\|(\d+)$").expect("the rule regex is valid");
+        let mut invalid_successors: HashMap<u16, Vec<u16>> = HashMap::new();
+
+        for (index, line) in rules.lines().enumerate() {
+            let capture =
+                line_regex
+                    .captures(line)
+                    .ok_or_else(|| PrintQueueError::InvalidRule {
+                        line: index + 1,
+                        text: line.to_string(),
+                    })?;
+            let lower = parse_page(&capture[1])?;
+            let higher = parse_page(&capture[2])?;
+            invalid_successors.entry(higher).or_default().push(lower);
+        }
+
+        // the updates start after the rules and the blank line
+        let first_update_line = rules.lines().count() + 2;
+        let mut updates: Vec<Vec<u16>> = Vec::new();
+        for (index, line) in update_lines.lines().enumerate() {
+            if line.trim().is_empty() {
+                return Err(PrintQueueError::EmptyUpdate {
+                    line: first_update_line + index,
+                });
+            }
+            let update = line
+                .split(',')
+                .map(|page| parse_page(page.trim()))
+                .collect::<Result<Vec<u16>, _>>()?;
+            updates.push(update);
+        }
+
+        Ok(PrintQueue {
+            invalid_successors,
+            updates,
+        })
+    }
+}
+
+impl PrintQueue {
+    pub fn from_files<P>(filename_rules: P, filename_update: P) -> anyhow::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Self::from_readers(File::open(filename_rules)?, File::open(filename_update)?)
+    }
+
+    /// Reads the rules and the updates from separate sources.
+    pub fn from_readers<R>(rules: R, updates: R) -> anyhow::Result<Self>
+    where
+        R: Read,
+    {
+        let rules = io::read_to_string(rules)?;
+        let updates = io::read_to_string(updates)?;
+        Ok(format!("{}\n\n{}", rules.trim_end(), updates).parse()?)
+    }
+
+    pub fn updates(&self) -> &[Vec<u16>] {
+        &self.updates
+    }
+
+    pub fn sum_of_correct_middle_pages(&self) -> i64 {
+        let invalid_successors = &self.invalid_successors;
+        let mut result_counter = 0;
+        'outer: for update in &self.updates {
+            for i in 0..update.len() - 1 {
+                let val = update[i];
+                for invalid_successor in invalid_successors.get(&val).unwrap_or(&Vec::new()) {
+                    if update[i + 1..].contains(invalid_successor) {
+                        continue 'outer;
+                    }
+                }
+            }
+            result_counter += i64::from(update[(update.len() - 1) / 2]);
+        }
+
+        result_counter
+    }
+
+    /// The incorrectly ordered updates, put into the order of the rules. Fails if the rules
+    /// order the pages of an update in a cycle, no order satisfies them then.
+    pub fn corrected_updates(&self) -> Result<Vec<Vec<u16>>, PrintQueueError> {
+        let invalid_successors = &self.invalid_successors;
+        let mut corrected_updates = Vec::new();
+        for (index, update) in self.updates.iter().enumerate() {
+            if let Some(pages) = find_cycle(invalid_successors, update) {
+                return Err(PrintQueueError::CyclicRules {
+                    update: index + 1,
+                    pages,
+                });
+            }
+            let mut update = update.clone();
+            let mut i = 0;
+            let mut is_relevant = false;
+            'while_loop: while i < update.len() - 1 {
+                let val = update[i];
+                if let Some(invalid_successors_val) = invalid_successors.get(&val) {
+                    for j in i + 1..update.len() {
+                        if invalid_successors_val.contains(&update[j]) {
+                            is_relevant = true;
+                            update.swap(i, j);
+                            i = 0;
+                            continue 'while_loop;
+                        }
+                    }
+                }
+                i += 1;
+            }
+            if is_relevant {
+                corrected_updates.push(update);
+            }
+        }
+
+        Ok(corrected_updates)
+    }
+
+    pub fn sum_of_corrected_middle_pages(&self) -> Result<i64, PrintQueueError> {
+        Ok(self
+            .corrected_updates()?
+            .iter()
+            .map(|update| i64::from(update[(update.len() - 1) / 2]))
+            .sum())
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const INPUT_FILES: &'static [&'static str] = &["data/data_rules_1", "data/data_update_1"];
+
+    type Input = PrintQueue;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(print_queue: &PrintQueue, _: &()) -> anyhow::Result<Answer> {
+        Ok(print_queue.sum_of_correct_middle_pages().into())
+    }
+
+    fn part2(print_queue: &PrintQueue, _: &()) -> anyhow::Result<Answer> {
+        Ok(print_queue.sum_of_corrected_middle_pages()?.into())
+    }
+}
+
+pub fn puzzle01<P>(filename_rules: P, filename_update: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_readers(File::open(filename_rules)?, File::open(filename_update)?)
+}
+
+pub fn puzzle01_from_readers<R>(rules: R, updates: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    Ok(PrintQueue::from_readers(rules, updates)?.sum_of_correct_middle_pages())
+}
+
+// rules and updates separated by a blank line
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<i64> {
+    Ok(input.parse::<PrintQueue>()?.sum_of_correct_middle_pages())
+}
+
+pub fn puzzle02<P>(filename_rules: P, filename_update: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_readers(File::open(filename_rules)?, File::open(filename_update)?)
+}
+
+pub fn puzzle02_from_readers<R>(rules: R, updates: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    Ok(PrintQueue::from_readers(rules, updates)?.sum_of_corrected_middle_pages()?)
+}
+
+// rules and updates separated by a blank line
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<i64> {
+    Ok(input
+        .parse::<PrintQueue>()?
+        .sum_of_corrected_middle_pages()?)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(
+            crate::puzzle01("data/test_data_rules_1", "data/test_data_update_1").unwrap(),
+            143
+        )
+    }
+
+    #[test]
+    fn test1_from_readers() {
+        let rules = include_str!("../data/test_data_rules_1");
+        let updates = include_str!("../data/test_data_update_1");
+        assert_eq!(
+            crate::puzzle01_from_readers(rules.as_bytes(), updates.as_bytes()).unwrap(),
+            143
+        )
+    }
+    #[test]
+    fn test2() {
+        assert_eq!(
+            crate::puzzle02("data/test_data_rules_1", "data/test_data_update_1").unwrap(),
+            123
+        )
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use crate::{PrintQueue, PrintQueueError};
+        use aoc_core::Location;
+
+        let error = "47|53\n97-13\n\n75,47\n".parse::<PrintQueue>().unwrap_err();
+        assert!(matches!(
+            error,
+            PrintQueueError::InvalidRule { line: 2, .. }
+        ));
+        let error = "47|53\n\n75,47\n75,x7\n".parse::<PrintQueue>().unwrap_err();
+        assert!(matches!(
+            error,
+            PrintQueueError::InvalidPage { location, .. } if location == Location::new(4, 4)
+        ));
+        let error = "47|53\n75,47\n".parse::<PrintQueue>().unwrap_err();
+        assert!(matches!(error, PrintQueueError::MissingSeparator));
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(
+            validate(&format!(
+                "{}\n\n{}",
+                include_str!("../data/test_data_rules_1").trim_end(),
+                include_str!("../data/test_data_update_1")
+            )),
+            []
+        );
+        assert_eq!(
+            validate("47|53\n\n75,47\n75,,47\n"),
+            [
+                Problem::at_line(3, "the update has 2 pages and no middle page"),
+                Problem::at_line(4, "the update has an empty page")
+            ]
+        );
+        assert_eq!(
+            validate("1|2\n3|4\n2|1\n\n1,3,2\n3,4,5\n"),
+            [Problem::at_line(
+                5,
+                "the rules on lines 1, 3 order the pages in a cycle"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_cyclic_rules() {
+        use crate::{PrintQueue, PrintQueueError};
+
+        let print_queue: PrintQueue = "1|2\n2|1\n\n3,4,5\n2,3,1\n".parse().unwrap();
+        assert_eq!(print_queue.sum_of_correct_middle_pages(), 4);
+        assert!(matches!(
+            print_queue.corrected_updates(),
+            Err(PrintQueueError::CyclicRules { update: 2, pages }) if pages == [2, 1, 2]
+        ));
+    }
+}
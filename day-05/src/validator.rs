@@ -0,0 +1,90 @@
+use crate::find_cycle;
+use aoc_core::{check_numbers, Problem};
+use std::collections::{HashMap, HashSet};
+
+/// Rules `X|Y`, a blank line and updates of an odd number of distinct, comma separated pages.
+/// The rules must not order the pages of an update in a cycle. The lines are numbered across
+/// both parts.
+pub fn validate(input: &str) -> Vec<Problem> {
+    let Some((rules, updates)) = input.split_once("\n\n") else {
+        return vec![Problem::new(
+            "the rules are not followed by a blank line and the updates",
+        )];
+    };
+
+    let mut problems = Vec::new();
+    // the first line of every valid rule and the pages ordered before a page like in the parser
+    let mut rule_lines: HashMap<(u16, u16), usize> = HashMap::new();
+    let mut invalid_successors: HashMap<u16, Vec<u16>> = HashMap::new();
+    for (index, rule) in rules.lines().enumerate() {
+        match rule.split_once('|') {
+            Some((before, after)) => {
+                if let (Ok(before), Ok(after)) = (before.parse(), after.parse()) {
+                    rule_lines.entry((before, after)).or_insert(index + 1);
+                    invalid_successors.entry(after).or_default().push(before);
+                }
+                problems.extend(check_numbers::<u16>(index + 1, before, "page"));
+                problems.extend(check_numbers::<u16>(index + 1, after, "page"));
+                if before.trim().is_empty() || after.trim().is_empty() {
+                    problems.push(Problem::at_line(index + 1, "the rule misses a page"));
+                } else if before.trim() == after.trim() {
+                    problems.push(Problem::at_line(
+                        index + 1,
+                        "the rule orders a page before itself",
+                    ));
+                }
+            }
+            None => problems.push(Problem::at_line(
+                index + 1,
+                format!("expected a rule X|Y, found {:?}", rule),
+            )),
+        }
+    }
+
+    let first_update_line = rules.lines().count() + 2;
+    for (index, update) in updates.lines().enumerate() {
+        let line = first_update_line + index;
+        if update.trim().is_empty() {
+            problems.push(Problem::at_line(line, "the update has no pages"));
+            continue;
+        }
+        let pages: Vec<_> = update.split(',').map(str::trim).collect();
+        for page in &pages {
+            problems.extend(check_numbers::<u16>(line, page, "page"));
+            if page.is_empty() {
+                problems.push(Problem::at_line(line, "the update has an empty page"));
+            }
+        }
+        if pages.len() % 2 == 0 {
+            problems.push(Problem::at_line(
+                line,
+                format!("the update has {} pages and no middle page", pages.len()),
+            ));
+        }
+        let distinct: HashSet<_> = pages.iter().collect();
+        if distinct.len() < pages.len() {
+            problems.push(Problem::at_line(line, "the update repeats a page"));
+        }
+        let Ok(pages) = pages
+            .iter()
+            .map(|page| page.parse())
+            .collect::<Result<Vec<u16>, _>>()
+        else {
+            continue;
+        };
+        if let Some(cycle) = find_cycle(&invalid_successors, &pages) {
+            let cycle_lines: Vec<_> = cycle
+                .windows(2)
+                .map(|rule| rule_lines[&(rule[0], rule[1])].to_string())
+                .collect();
+            problems.push(Problem::at_line(
+                line,
+                format!(
+                    "the rules on lines {} order the pages in a cycle",
+                    cycle_lines.join(", ")
+                ),
+            ));
+        }
+    }
+    problems
+}
@@ -0,0 +1,11 @@
+use day_05::PrintQueue;
+
+#[test]
+fn test_print_queue() {
+    let print_queue =
+        PrintQueue::from_files("data/test_data_rules_1", "data/test_data_update_1").unwrap();
+    assert_eq!(print_queue.updates().len(), 6);
+    assert_eq!(print_queue.updates()[0], [75, 47, 61, 53, 29]);
+    assert_eq!(print_queue.sum_of_correct_middle_pages(), 143);
+    assert_eq!(print_queue.sum_of_corrected_middle_pages().unwrap(), 123);
+}
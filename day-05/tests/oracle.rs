@@ -0,0 +1,71 @@
+use day_05::PrintQueue;
+use proptest::prelude::*;
+
+// the position of a page in the order given by the rules
+fn rank(order: &[u16], page: u16) -> Option<usize> {
+    order.iter().position(|other| *other == page)
+}
+
+fn is_ordered(order: &[u16], update: &[u16]) -> bool {
+    update
+        .windows(2)
+        .all(|pair| rank(order, pair[0]) < rank(order, pair[1]))
+}
+
+fn oracle_sums(order: &[u16], updates: &[Vec<u16>]) -> (i64, i64) {
+    let mut correct = 0;
+    let mut corrected = 0;
+    for update in updates {
+        let middle = (update.len() - 1) / 2;
+        if is_ordered(order, update) {
+            correct += i64::from(update[middle]);
+        } else {
+            let mut sorted = update.clone();
+            sorted.sort_by_key(|page| rank(order, *page));
+            corrected += i64::from(sorted[middle]);
+        }
+    }
+    (correct, corrected)
+}
+
+// a total order of pages and updates of an odd number of distinct pages
+fn print_queue() -> impl Strategy<Value = (Vec<u16>, Vec<Vec<u16>>)> {
+    prop::sample::subsequence((10..100).collect::<Vec<u16>>(), 3..8)
+        .prop_shuffle()
+        .prop_flat_map(|order| {
+            let pages = order.len();
+            let update = (Just(order.clone()).prop_shuffle(), 0..pages.div_ceil(2))
+                .prop_map(|(pages, half)| pages[..2 * half + 1].to_vec());
+            (Just(order), prop::collection::vec(update, 1..6))
+        })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_oracle((order, updates) in print_queue()) {
+        let mut rules = Vec::new();
+        for (index, before) in order.iter().enumerate() {
+            for after in &order[index + 1..] {
+                rules.push(format!("{}|{}", before, after));
+            }
+        }
+        let updates_text: Vec<_> = updates
+            .iter()
+            .map(|update| {
+                update
+                    .iter()
+                    .map(u16::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect();
+        let input = format!("{}\n\n{}\n", rules.join("\n"), updates_text.join("\n"));
+        let print_queue: PrintQueue = input.parse().unwrap();
+
+        let (correct, corrected) = oracle_sums(&order, &updates);
+        prop_assert_eq!(print_queue.sum_of_correct_middle_pages(), correct);
+        prop_assert_eq!(print_queue.sum_of_corrected_middle_pages().unwrap(), corrected);
+    }
+}
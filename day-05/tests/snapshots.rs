@@ -0,0 +1,11 @@
+use day_05::PrintQueue;
+
+#[test]
+fn test_corrected_updates() {
+    let print_queue =
+        PrintQueue::from_files("data/test_data_rules_1", "data/test_data_update_1").unwrap();
+    insta::assert_debug_snapshot!(
+        "corrected_updates",
+        print_queue.corrected_updates().unwrap()
+    );
+}
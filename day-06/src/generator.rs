@@ -0,0 +1,47 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A `size` x `size` lab map with scattered obstacles, the guard `^` leaves it eventually.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(1);
+    loop {
+        let mut map: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.gen_ratio(1, 12) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let (x, y) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        map[y][x] = '^';
+        if leaves_map(&map, x, y) {
+            return map
+                .iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect();
+        }
+    }
+}
+
+// whether the guard walks off the map instead of patrolling in a loop
+fn leaves_map(map: &[Vec<char>], mut x: usize, mut y: usize) -> bool {
+    let size = map.len();
+    let (mut dx, mut dy) = (0isize, -1isize);
+    // a loop repeats a position and direction, there are at most 4 per position
+    for _ in 0..4 * size * size {
+        let (Some(next_x), Some(next_y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy))
+        else {
+            return true;
+        };
+        if size <= next_x || size <= next_y {
+            return true;
+        }
+        if map[next_y][next_x] == '#' {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (next_x, next_y);
+        }
+    }
+    false
+}
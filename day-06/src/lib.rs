@@ -0,0 +1,314 @@
+pub mod generator;
+pub mod validator;
+
+use aoc_core::{logging::Progress, Answer, Problem, Solution};
+use grid::{Direction, Grid, GridError, Point};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{self, Read},
+    path::Path,
+    str::FromStr,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum GuardMapError {
+    #[error("could not parse the lab map")]
+    InvalidMap(#[from] GridError),
+    #[error("there is no guard `^` on the lab map")]
+    MissingGuard,
+}
+
+/// The patrolling guard: where it stands and where it is heading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guard {
+    direction: Direction,
+    position: Point,
+}
+
+impl Guard {
+    pub fn new(position: Point, direction: Direction) -> Self {
+        Guard {
+            direction,
+            position,
+        }
+    }
+
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// The position in front of the guard, `None` at the upper and left border.
+    pub fn get_forward_position(&self) -> Option<Point> {
+        self.position.step(self.direction)
+    }
+
+    /// Turns right by 90 degrees.
+    pub fn turn(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FieldType {
+    Free,
+    Obstacle,
+}
+
+/// The lab map with its obstacles and the starting guard.
+#[derive(Debug, Clone)]
+pub struct GuardMap {
+    fields: Grid<FieldType>,
+    guard: Guard,
+}
+
+impl FromStr for GuardMap {
+    type Err = GuardMapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = Grid::parse(s, |char| match char {
+            '#' => Some(FieldType::Obstacle),
+            '.' | '^' => Some(FieldType::Free),
+            _ => None,
+        })?;
+        let position = s
+            .lines()
+            .enumerate()
+            .find_map(|(y, line)| line.find('^').map(|x| Point::new(x, y)))
+            .ok_or(GuardMapError::MissingGuard)?;
+
+        Ok(GuardMap {
+            fields,
+            guard: Guard {
+                direction: Direction::Up,
+                position,
+            },
+        })
+    }
+}
+
+impl GuardMap {
+    pub fn guard(&self) -> &Guard {
+        &self.guard
+    }
+
+    pub fn guard_position(&self) -> Point {
+        self.guard.position
+    }
+
+    pub fn fields(&self) -> &Grid<FieldType> {
+        &self.fields
+    }
+
+    pub fn count_visited_positions(&self) -> usize {
+        self.visited_positions().len()
+    }
+
+    /// The positions on the path of the guard until it leaves the map.
+    pub fn visited_positions(&self) -> HashSet<Point> {
+        let guard_map = &self.fields;
+        let mut guard = self.guard.clone();
+        let mut visited = HashSet::new();
+        loop {
+            visited.insert(guard.position);
+            match guard.get_forward_position() {
+                Some(point) => match guard_map.get(point) {
+                    Some(field_type) => match field_type {
+                        FieldType::Free => guard.position = point,
+                        FieldType::Obstacle => guard.turn(),
+                    },
+                    None => break,
+                },
+                None => break,
+            }
+        }
+
+        visited
+    }
+
+    pub fn count_loop_obstructions(&self) -> i64 {
+        self.loop_obstructions().len() as i64
+    }
+
+    /// The positions where a new obstruction makes the guard patrol in a loop, in the order of
+    /// the map. The candidates are simulated concurrently with the `parallel` feature.
+    pub fn loop_obstructions(&self) -> Vec<Point> {
+        let guard_map = &self.fields;
+        let number_of_keys = guard_map.width() * guard_map.height();
+        let progress = Progress::new(number_of_keys as u64, "loop obstructions");
+        let keys: Vec<Point> = guard_map.iter().map(|(key, _)| key).collect();
+        #[cfg(feature = "parallel")]
+        let keys = keys.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let keys = keys.into_iter();
+        keys.filter(|key| {
+            progress.inc(1);
+            let is_cycle = *key != self.guard.position
+                && guard_map[*key] != FieldType::Obstacle
+                && self.is_loop_with_obstruction(*key);
+            if is_cycle {
+                tracing::trace!(x = key.x, y = key.y, "obstruction makes a loop");
+            }
+            is_cycle
+        })
+        .collect()
+    }
+
+    // whether the guard patrols in a loop after placing an obstruction at `key`
+    fn is_loop_with_obstruction(&self, key: Point) -> bool {
+        let mut guard_clone = self.guard.clone();
+        let mut guard_map_clone = self.fields.clone();
+        guard_map_clone[key] = FieldType::Obstacle;
+
+        // cycle detection
+        let mut visited: HashMap<Point, Vec<Direction>> = HashMap::new();
+        loop {
+            visited
+                .entry(guard_clone.position)
+                .or_default()
+                .push(guard_clone.direction);
+            match guard_clone.get_forward_position() {
+                Some(point) => match guard_map_clone.get(point) {
+                    Some(field_type) => match field_type {
+                        FieldType::Free => guard_clone.position = point,
+                        FieldType::Obstacle => guard_clone.turn(),
+                    },
+                    None => return false,
+                },
+                None => return false,
+            }
+            if let Some(direction_vec) = visited.get(&guard_clone.position) {
+                if direction_vec.contains(&guard_clone.direction) {
+                    return true;
+                }
+            }
+        }
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = GuardMap;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(guard_map: &GuardMap, _: &()) -> anyhow::Result<Answer> {
+        Ok(guard_map.count_visited_positions().into())
+    }
+
+    fn part2(guard_map: &GuardMap, _: &()) -> anyhow::Result<Answer> {
+        Ok(guard_map.count_loop_obstructions().into())
+    }
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<usize>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<usize>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<usize> {
+    let guard_map: GuardMap = input.parse()?;
+    Ok(guard_map.count_visited_positions())
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<i64>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<i64>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<i64> {
+    let guard_map: GuardMap = input.parse()?;
+    Ok(guard_map.count_loop_obstructions())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 41)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 41)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1").unwrap(), 6)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use crate::{GuardMap, GuardMapError};
+        use grid::GridError;
+
+        assert_eq!(
+            "..#\n.^v\n".parse::<GuardMap>().err(),
+            Some(GuardMapError::InvalidMap(GridError::InvalidChar {
+                line: 2,
+                column: 3,
+                char: 'v'
+            }))
+        );
+        assert_eq!(
+            "..#\n...\n".parse::<GuardMap>().err(),
+            Some(GuardMapError::MissingGuard)
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("..^\n.^.\n"),
+            [
+                Problem::at_line(1, "the lab map has more than one guard `^`"),
+                Problem::at_line(2, "the lab map has more than one guard `^`")
+            ]
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_core::{check_grid, Problem};
+
+/// A rectangular lab map of free positions `.` and obstructions `#` with exactly one guard `^`.
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = check_grid(input, |char| matches!(char, '.' | '#' | '^'));
+    let guards: Vec<_> = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.contains('^'))
+        .map(|(index, _)| index + 1)
+        .collect();
+    match guards.as_slice() {
+        [] => problems.push(Problem::new("there is no guard `^` on the lab map")),
+        [_] if input.matches('^').count() == 1 => {}
+        _ => problems.extend(
+            guards
+                .iter()
+                .map(|line| Problem::at_line(*line, "the lab map has more than one guard `^`")),
+        ),
+    }
+    problems
+}
@@ -0,0 +1,21 @@
+use day_06::{FieldType, Guard, GuardMap};
+use grid::{Direction, Point};
+
+#[test]
+fn test_guard() {
+    let mut guard = Guard::new(Point::new(4, 6), Direction::Up);
+    assert_eq!(guard.get_forward_position(), Some(Point::new(4, 5)));
+    guard.turn();
+    assert_eq!(guard.direction(), Direction::Right);
+}
+
+#[test]
+fn test_guard_map() {
+    let guard_map: GuardMap = include_str!("../data/test_data_1").parse().unwrap();
+    assert_eq!(
+        guard_map.guard(),
+        &Guard::new(Point::new(4, 6), Direction::Up)
+    );
+    assert_eq!(guard_map.fields()[Point::new(4, 0)], FieldType::Obstacle);
+    assert_eq!(guard_map.count_visited_positions(), 41);
+}
@@ -0,0 +1,86 @@
+use day_06::GuardMap;
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+// the positions the guard visits before leaving the map, `None` if it patrols in a loop
+fn walk(obstacles: &[Vec<bool>], start: (usize, usize)) -> Option<HashSet<(usize, usize)>> {
+    let (width, height) = (obstacles[0].len() as isize, obstacles.len() as isize);
+    let (mut x, mut y) = (start.0 as isize, start.1 as isize);
+    let (mut dx, mut dy) = (0, -1);
+    let mut states = HashSet::new();
+    while states.insert((x, y, dx, dy)) {
+        let (next_x, next_y) = (x + dx, y + dy);
+        if !(0..width).contains(&next_x) || !(0..height).contains(&next_y) {
+            return Some(
+                states
+                    .iter()
+                    .map(|(x, y, _, _)| (*x as usize, *y as usize))
+                    .collect(),
+            );
+        }
+        if obstacles[next_y as usize][next_x as usize] {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (next_x, next_y);
+        }
+    }
+    None
+}
+
+// tries an obstruction on every free position except the start
+fn oracle_count_loop_obstructions(obstacles: &[Vec<bool>], start: (usize, usize)) -> i64 {
+    let mut count = 0;
+    for y in 0..obstacles.len() {
+        for x in 0..obstacles[0].len() {
+            if (x, y) != start && !obstacles[y][x] {
+                let mut obstructed = obstacles.to_vec();
+                obstructed[y][x] = true;
+                if walk(&obstructed, start).is_none() {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+fn lab_map() -> impl Strategy<Value = (Vec<Vec<bool>>, (usize, usize))> {
+    (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+        let row = prop::collection::vec(prop::bool::weighted(0.2), width);
+        (prop::collection::vec(row, height), (0..width, 0..height))
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+
+    #[test]
+    fn test_oracle((mut obstacles, start) in lab_map()) {
+        obstacles[start.1][start.0] = false;
+        let visited = walk(&obstacles, start);
+        prop_assume!(visited.is_some(), "the guard does not leave the map");
+
+        let input: String = obstacles
+            .iter()
+            .enumerate()
+            .map(|(y, row)| {
+                let row: String = row
+                    .iter()
+                    .enumerate()
+                    .map(|(x, obstacle)| match (obstacle, (x, y) == start) {
+                        (_, true) => '^',
+                        (true, false) => '#',
+                        (false, false) => '.',
+                    })
+                    .collect();
+                row + "\n"
+            })
+            .collect();
+        let guard_map: GuardMap = input.parse().unwrap();
+        prop_assert_eq!(guard_map.count_visited_positions(), visited.map_or(0, |visited| visited.len()));
+        prop_assert_eq!(
+            guard_map.count_loop_obstructions(),
+            oracle_count_loop_obstructions(&obstacles, start)
+        );
+    }
+}
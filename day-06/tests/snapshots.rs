@@ -0,0 +1,43 @@
+use day_06::GuardMap;
+use grid::Point;
+use std::collections::HashSet;
+
+// the lab map with `marker` on the given positions
+fn render(input: &str, positions: &HashSet<Point>, marker: char) -> String {
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            let row: String = line
+                .chars()
+                .enumerate()
+                .map(|(x, char)| {
+                    if positions.contains(&Point::new(x, y)) {
+                        marker
+                    } else {
+                        char
+                    }
+                })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn test_visited_path() {
+    let input = include_str!("../data/test_data_1");
+    let guard_map: GuardMap = input.parse().unwrap();
+    insta::assert_snapshot!(
+        "visited_path",
+        render(input, &guard_map.visited_positions(), 'X')
+    );
+}
+
+#[test]
+fn test_loop_obstructions() {
+    let input = include_str!("../data/test_data_1");
+    let guard_map: GuardMap = input.parse().unwrap();
+    let obstructions = guard_map.loop_obstructions().into_iter().collect();
+    insta::assert_snapshot!("loop_obstructions", render(input, &obstructions, 'O'));
+}
@@ -0,0 +1,28 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` equations of 2 to 7 operands below 100. A third of the test values are made with
+/// `+` and `*`, a third also with `||` and the others are random, so no sum overflows.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut input = String::new();
+    for _ in 0..size {
+        let length = rng.gen_range(2..=7);
+        let operands: Vec<u64> = (0..length).map(|_| rng.gen_range(1..100)).collect();
+        let operators = rng.gen_range(0..3);
+        let test_value = if operators == 0 {
+            rng.gen_range(1..1_000_000)
+        } else {
+            operands[1..].iter().fold(operands[0], |value, operand| {
+                match rng.gen_range(0..operators + 1) {
+                    0 => value + operand,
+                    1 => value * operand,
+                    _ => format!("{}{}", value, operand).parse().unwrap_or(value),
+                }
+            })
+        };
+        let operands: Vec<_> = operands.iter().map(u64::to_string).collect();
+        input.push_str(&format!("{}: {}\n", test_value, operands.join(" ")));
+    }
+    input
+}
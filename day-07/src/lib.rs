@@ -0,0 +1,350 @@
+pub mod generator;
+pub mod validator;
+
+use aoc_core::{Answer, Location, Problem, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    fs::File,
+    io::{self, Read},
+    num::ParseIntError,
+    path::Path,
+    str::FromStr,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum EquationError {
+    #[error("line {line} is no equation `test value: operands`: {text:?}")]
+    MissingColon { line: usize, text: String },
+    #[error("equation on line {line} has no operands")]
+    MissingOperands { line: usize },
+    #[error("invalid number {text:?} at {location}")]
+    InvalidNumber {
+        location: Location,
+        text: String,
+        source: ParseIntError,
+    },
+    #[error("the total calibration result does not fit into 64 bits")]
+    TotalOverflow,
+}
+
+// the operators work on `None` once a value overflows, it is then too large to be the test
+// value unless a later operand of 0 multiplies it away
+fn multiply(val: Option<u64>, next_val: u64) -> Option<u64> {
+    if next_val == 0 {
+        Some(0)
+    } else {
+        val?.checked_mul(next_val)
+    }
+}
+
+fn concatenate(val: Option<u64>, next_val: u64) -> Option<u64> {
+    let shift = u64::checked_pow(10, next_val.checked_ilog10().unwrap_or(0) + 1)?;
+    val?.checked_mul(shift)?.checked_add(next_val)
+}
+
+// an overflowed value can be pruned if no operand of 0 is left
+fn is_pruned(val: Option<u64>, tail: &[u64]) -> bool {
+    val.is_none() && !tail.contains(&0)
+}
+
+fn add(val: Option<u64>, mut tail: Vec<u64>, result: u64) -> bool {
+    match tail.pop() {
+        Some(next_val) => {
+            let new_val = val.and_then(|val| val.checked_add(next_val));
+            if is_pruned(new_val, &tail) {
+                return false;
+            }
+            add(new_val, tail.clone(), result) || mul(new_val, tail.clone(), result)
+        }
+        None => val == Some(result),
+    }
+}
+
+fn mul(val: Option<u64>, mut tail: Vec<u64>, result: u64) -> bool {
+    match tail.pop() {
+        Some(next_val) => {
+            let new_val = multiply(val, next_val);
+            if is_pruned(new_val, &tail) {
+                return false;
+            }
+            add(new_val, tail.clone(), result) || mul(new_val, tail.clone(), result)
+        }
+        None => val == Some(result),
+    }
+}
+
+fn add2(val: Option<u64>, mut tail: Vec<u64>, result: u64) -> bool {
+    match tail.pop() {
+        Some(next_val) => {
+            let new_val = val.and_then(|val| val.checked_add(next_val));
+            if is_pruned(new_val, &tail) {
+                return false;
+            }
+            add2(new_val, tail.clone(), result)
+                || mul2(new_val, tail.clone(), result)
+                || concat2(new_val, tail, result)
+        }
+        None => val == Some(result),
+    }
+}
+
+fn mul2(val: Option<u64>, mut tail: Vec<u64>, result: u64) -> bool {
+    match tail.pop() {
+        Some(next_val) => {
+            let new_val = multiply(val, next_val);
+            if is_pruned(new_val, &tail) {
+                return false;
+            }
+            add2(new_val, tail.clone(), result)
+                || mul2(new_val, tail.clone(), result)
+                || concat2(new_val, tail, result)
+        }
+        None => val == Some(result),
+    }
+}
+
+fn concat2(val: Option<u64>, mut tail: Vec<u64>, result: u64) -> bool {
+    match tail.pop() {
+        Some(next_val) => {
+            let new_val = concatenate(val, next_val);
+            if is_pruned(new_val, &tail) {
+                return false;
+            }
+            add2(new_val, tail.clone(), result)
+                || mul2(new_val, tail.clone(), result)
+                || concat2(new_val, tail, result)
+        }
+        None => val == Some(result),
+    }
+}
+
+/// A calibration equation: the test value and the operands in between the missing operators.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation {
+    test_value: u64,
+    // stored in reverse order, the operators take the operands from the back
+    operands: Vec<u64>,
+}
+
+impl FromStr for Equation {
+    type Err = EquationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Equation::parse_line(s, s, 1)
+    }
+}
+
+impl Equation {
+    // `line` is a subslice of `input`, errors are located relative to `input`
+    fn parse_line(input: &str, line: &str, line_number: usize) -> Result<Self, EquationError> {
+        let parse_number = |number: &str| {
+            number
+                .parse::<u64>()
+                .map_err(|source| EquationError::InvalidNumber {
+                    location: Location::of(input, number),
+                    text: number.to_string(),
+                    source,
+                })
+        };
+
+        let (test_value, operands) =
+            line.split_once(':')
+                .ok_or_else(|| EquationError::MissingColon {
+                    line: line_number,
+                    text: line.to_string(),
+                })?;
+        let mut temp_vec: Vec<u64> = Vec::new();
+        for element in operands.split_whitespace() {
+            temp_vec.push(parse_number(element)?);
+        }
+        if temp_vec.is_empty() {
+            return Err(EquationError::MissingOperands { line: line_number });
+        }
+        temp_vec.reverse();
+        Ok(Equation {
+            test_value: parse_number(test_value.trim())?,
+            operands: temp_vec,
+        })
+    }
+
+    pub fn test_value(&self) -> u64 {
+        self.test_value
+    }
+
+    pub fn operands(&self) -> impl Iterator<Item = &u64> {
+        self.operands.iter().rev()
+    }
+
+    // the evaluation starts by adding the first operand to 0, multiplying it would drop it
+    pub fn is_solvable(&self) -> bool {
+        add(Some(0), self.operands.clone(), self.test_value)
+    }
+
+    pub fn is_solvable_with_concatenation(&self) -> bool {
+        add2(Some(0), self.operands.clone(), self.test_value)
+    }
+}
+
+/// Parses one equation per line.
+pub fn parse_equations(input: &str) -> Result<Vec<Equation>, EquationError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Equation::parse_line(input, line, index + 1))
+        .collect()
+}
+
+/// The sum of the test values of the solvable equations.
+pub fn total_calibration_result(equations: &[Equation]) -> Result<u64, EquationError> {
+    sum_test_values(equations, Equation::is_solvable)
+}
+
+pub fn total_calibration_result_with_concatenation(
+    equations: &[Equation],
+) -> Result<u64, EquationError> {
+    sum_test_values(equations, Equation::is_solvable_with_concatenation)
+}
+
+// summed up in u128, which cannot overflow for any number of u64 test values that fits in memory
+fn sum_test_values<F>(equations: &[Equation], is_solvable: F) -> Result<u64, EquationError>
+where
+    F: Fn(&Equation) -> bool + Sync,
+{
+    #[cfg(feature = "parallel")]
+    let equations = equations.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let equations = equations.iter();
+    let total: u128 = equations
+        .filter(|equation| is_solvable(equation))
+        .map(|equation| u128::from(equation.test_value))
+        .sum();
+    u64::try_from(total).map_err(|_| EquationError::TotalOverflow)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_equations(input)?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(equations: &Self::Input, _: &()) -> anyhow::Result<Answer> {
+        Ok(total_calibration_result(equations)?.into())
+    }
+
+    fn part2(equations: &Self::Input, _: &()) -> anyhow::Result<Answer> {
+        Ok(total_calibration_result_with_concatenation(equations)?.into())
+    }
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<u64>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<u64>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<u64> {
+    let equations = parse_equations(input)?;
+    Ok(total_calibration_result(&equations)?)
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<u64>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<u64>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<u64> {
+    let equations = parse_equations(input)?;
+    Ok(total_calibration_result_with_concatenation(&equations)?)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 3749)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 3749)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1").unwrap(), 11387)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use crate::{parse_equations, EquationError};
+        use aoc_core::Location;
+
+        let error = parse_equations("190: 10 19\n3267 81 40 27\n").unwrap_err();
+        assert!(matches!(error, EquationError::MissingColon { line: 2, .. }));
+        let error = parse_equations("190: 10 19\n3267: 81 4o 27\n").unwrap_err();
+        assert!(matches!(
+            error,
+            EquationError::InvalidNumber { location, .. } if location == Location::new(2, 10)
+        ));
+    }
+
+    #[test]
+    fn test_leading_multiplication_by_zero() {
+        use crate::Equation;
+
+        let equation: Equation = "5: 3 5".parse().unwrap();
+        assert!(!equation.is_solvable());
+        assert!(!equation.is_solvable_with_concatenation());
+        let equation: Equation = "35: 3 5".parse().unwrap();
+        assert!(equation.is_solvable_with_concatenation());
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("190: 10 19\n83 17 5\n156:\n"),
+            [
+                Problem::at_line(2, "expected `test value: operands`, found \"83 17 5\""),
+                Problem::at_line(3, "the equation has no operands")
+            ]
+        );
+    }
+}
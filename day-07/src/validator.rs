@@ -0,0 +1,24 @@
+use aoc_core::{check_numbers, Problem};
+
+/// Every line is an equation `test value: operands` with at least one operand.
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let Some((test_value, operands)) = line.split_once(':') else {
+            problems.push(Problem::at_line(
+                index + 1,
+                format!("expected `test value: operands`, found {:?}", line),
+            ));
+            continue;
+        };
+        if test_value.trim().is_empty() {
+            problems.push(Problem::at_line(index + 1, "the test value is missing"));
+        }
+        problems.extend(check_numbers::<u64>(index + 1, test_value, "test value"));
+        if operands.trim().is_empty() {
+            problems.push(Problem::at_line(index + 1, "the equation has no operands"));
+        }
+        problems.extend(check_numbers::<u64>(index + 1, operands, "operand"));
+    }
+    problems
+}
@@ -0,0 +1,19 @@
+use day_07::{parse_equations, total_calibration_result, Equation};
+
+#[test]
+fn test_equations() {
+    let equation: Equation = "3267: 81 40 27".parse().unwrap();
+    assert_eq!(equation.test_value(), 3267);
+    assert_eq!(
+        equation.operands().copied().collect::<Vec<_>>(),
+        [81, 40, 27]
+    );
+    assert!(equation.is_solvable());
+
+    let equation: Equation = "7290: 6 8 6 15".parse().unwrap();
+    assert!(!equation.is_solvable());
+    assert!(equation.is_solvable_with_concatenation());
+
+    let equations = parse_equations(include_str!("../data/test_data_1")).unwrap();
+    assert_eq!(total_calibration_result(&equations).unwrap(), 3749);
+}
@@ -0,0 +1,99 @@
+use day_07::Equation;
+use proptest::prelude::*;
+
+// applies the operators left to right: 0 adds, 1 multiplies and 2 concatenates. The values are
+// exact in u128 up to the first overflow of u64, `None` stands for any larger value.
+fn evaluate(operands: &[u64], operators: &[u8]) -> Option<u64> {
+    let mut value = Some(operands[0]);
+    for (operand, operator) in operands[1..].iter().zip(operators) {
+        let operand = u128::from(*operand);
+        let exact_value = match (value, operator) {
+            (_, 1) if operand == 0 => 0,
+            (None, _) => continue,
+            (Some(value), 0) => u128::from(value) + operand,
+            (Some(value), 1) => u128::from(value) * operand,
+            (Some(value), _) => format!("{}{}", value, operand).parse().unwrap_or(u128::MAX),
+        };
+        value = u64::try_from(exact_value).ok();
+    }
+    value
+}
+
+// tries every combination of the first `kinds` operators
+fn oracle_is_solvable(test_value: u64, operands: &[u64], kinds: u8) -> bool {
+    let slots = operands.len() - 1;
+    (0..u32::from(kinds).pow(slots as u32)).any(|combination| {
+        let operators: Vec<u8> = (0..slots)
+            .map(|slot| (combination / u32::from(kinds).pow(slot as u32) % u32::from(kinds)) as u8)
+            .collect();
+        evaluate(operands, &operators) == Some(test_value)
+    })
+}
+
+// mostly small operands, some of them 0 or close to u64::MAX
+fn operand() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        8 => 1..20u64,
+        1 => Just(0u64),
+        1 => (u64::MAX - 20)..=u64::MAX,
+        1 => (1u64 << 32)..(1u64 << 33),
+    ]
+}
+
+// half of the test values are the result of random operators
+fn equation() -> impl Strategy<Value = (u64, Vec<u64>)> {
+    (
+        prop::collection::vec(operand(), 1..6),
+        prop::collection::vec(0..3u8, 5),
+        any::<bool>(),
+        prop_oneof![1..5000u64, (u64::MAX - 5000)..=u64::MAX],
+    )
+        .prop_map(|(operands, operators, is_solved, random_value)| {
+            let test_value = match evaluate(&operands, &operators) {
+                Some(value) if is_solved => value,
+                _ => random_value,
+            };
+            (test_value, operands)
+        })
+}
+
+#[test]
+fn test_first_operand_is_kept() {
+    let equation: Equation = "3564: 10 2 18 11 9".parse().unwrap();
+    assert!(!equation.is_solvable());
+    assert!(!equation.is_solvable_with_concatenation());
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_oracle((test_value, operands) in equation()) {
+        let operands_text: Vec<_> = operands.iter().map(u64::to_string).collect();
+        let equation: Equation = format!("{}: {}", test_value, operands_text.join(" "))
+            .parse()
+            .unwrap();
+        prop_assert_eq!(equation.is_solvable(), oracle_is_solvable(test_value, &operands, 2));
+        prop_assert_eq!(
+            equation.is_solvable_with_concatenation(),
+            oracle_is_solvable(test_value, &operands, 3)
+        );
+    }
+}
+
+#[test]
+fn test_overflowing_operands() {
+    let equation: Equation = format!("{}: {} 2", u64::MAX, u64::MAX).parse().unwrap();
+    assert!(!equation.is_solvable());
+    assert!(!equation.is_solvable_with_concatenation());
+    // the overflowing value is multiplied by 0 and the last operand added
+    let equation: Equation = format!("7: {} 2 0 7", u64::MAX).parse().unwrap();
+    assert!(equation.is_solvable());
+    let equation: Equation = format!("{}: 1844674407370955161 5", u64::MAX)
+        .parse()
+        .unwrap();
+    assert!(equation.is_solvable_with_concatenation());
+
+    let equations = day_07::parse_equations(&format!("{0}: {0}\n{0}: {0}\n", u64::MAX)).unwrap();
+    assert!(day_07::total_calibration_result(&equations).is_err());
+}
@@ -0,0 +1,26 @@
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A `size` x `size` antenna map with about one antenna in 40 positions, out of eight
+/// frequencies.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let frequencies: Vec<char> = ('0'..='9')
+        .chain('a'..='z')
+        .chain('A'..='Z')
+        .collect::<Vec<_>>()
+        .choose_multiple(&mut rng, 8)
+        .copied()
+        .collect();
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            match frequencies.choose(&mut rng) {
+                Some(frequency) if rng.gen_ratio(1, 40) => input.push(*frequency),
+                _ => input.push('.'),
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,212 @@
+pub mod generator;
+pub mod validator;
+
+use aoc_core::{Answer, Problem, Solution};
+use grid::{Grid, GridError, Offset, Point};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{self, Read},
+    path::Path,
+    str::FromStr,
+};
+
+// the greatest common divisor, the offsets between antennas are not both zero
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The city map with the antenna positions grouped by frequency.
+#[derive(Debug, Clone)]
+pub struct AntennaMap {
+    map: Grid<char>,
+    antennas: HashMap<char, Vec<Point>>,
+}
+
+impl FromStr for AntennaMap {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(s, Some)?;
+        let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+        for (point, frequency) in map.iter() {
+            if *frequency != '.' {
+                antennas.entry(*frequency).or_default().push(point);
+            }
+        }
+
+        Ok(AntennaMap { map, antennas })
+    }
+}
+
+impl AntennaMap {
+    pub fn antennas(&self) -> &HashMap<char, Vec<Point>> {
+        &self.antennas
+    }
+
+    pub fn count_antinodes(&self) -> usize {
+        self.antinodes().len()
+    }
+
+    /// Positions in line with two antennas of a frequency, twice as far from one as from the other.
+    pub fn antinodes(&self) -> HashSet<Point> {
+        let mut antinodes = HashSet::new();
+        for vec in self.antennas.values() {
+            for point in vec {
+                for other_point in vec {
+                    if point != other_point {
+                        let antinode_point = point.checked_add(other_point.offset_to(*point));
+                        if let Some(antinode_point) =
+                            antinode_point.filter(|antinode| self.map.contains(*antinode))
+                        {
+                            antinodes.insert(antinode_point);
+                        }
+                    }
+                }
+            }
+        }
+
+        antinodes
+    }
+
+    pub fn count_resonant_antinodes(&self) -> usize {
+        self.resonant_antinodes().len()
+    }
+
+    /// Positions exactly in line with two antennas of a frequency, the antennas included.
+    pub fn resonant_antinodes(&self) -> HashSet<Point> {
+        let mut antinodes = HashSet::new();
+        for vec in self.antennas.values() {
+            for point in vec {
+                for other_point in vec {
+                    if point != other_point {
+                        // walks from the antenna past the other one, the reverse pair covers
+                        // the opposite direction
+                        let offset = point.offset_to(*other_point);
+                        let divisor = gcd(offset.dx.unsigned_abs(), offset.dy.unsigned_abs());
+                        let step =
+                            Offset::new(offset.dx / divisor as isize, offset.dy / divisor as isize);
+                        let mut antinode_point = Some(*point);
+                        while let Some(antinode) =
+                            antinode_point.filter(|antinode| self.map.contains(*antinode))
+                        {
+                            antinodes.insert(antinode);
+                            antinode_point = antinode.checked_add(step);
+                        }
+                    }
+                }
+            }
+        }
+
+        antinodes
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = AntennaMap;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(antenna_map: &AntennaMap, _: &()) -> anyhow::Result<Answer> {
+        Ok(antenna_map.count_antinodes().into())
+    }
+
+    fn part2(antenna_map: &AntennaMap, _: &()) -> anyhow::Result<Answer> {
+        Ok(antenna_map.count_resonant_antinodes().into())
+    }
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<usize>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<usize>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<usize> {
+    let antenna_map: AntennaMap = input.parse()?;
+    Ok(antenna_map.count_antinodes())
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<usize>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<usize>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<usize> {
+    let antenna_map: AntennaMap = input.parse()?;
+    Ok(antenna_map.count_resonant_antinodes())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 14)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 14)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1").unwrap(), 34)
+    }
+
+    #[test]
+    fn test_resonant_antinodes() {
+        // the antennas of `A` resonate at every point of the diagonal, the lone `B` nowhere
+        let input = "A...B\n.....\n..A..\n.....\n.....\n";
+        assert_eq!(crate::puzzle02_from_str(input).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("..a.\n.#..\n"),
+            [Problem::at_line(2, "invalid character '#' in column 2")]
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_core::{check_grid, Problem};
+
+/// A rectangular map of empty positions `.` and antennas named by letters or digits.
+pub fn validate(input: &str) -> Vec<Problem> {
+    check_grid(input, |char| char == '.' || char.is_ascii_alphanumeric())
+}
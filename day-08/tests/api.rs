@@ -0,0 +1,11 @@
+use day_08::AntennaMap;
+use grid::Point;
+
+#[test]
+fn test_antenna_map() {
+    let antenna_map: AntennaMap = include_str!("../data/test_data_1").parse().unwrap();
+    assert_eq!(antenna_map.antennas().len(), 2);
+    assert_eq!(antenna_map.antennas()[&'A'][0], Point::new(6, 5));
+    assert_eq!(antenna_map.count_antinodes(), 14);
+    assert_eq!(antenna_map.count_resonant_antinodes(), 34);
+}
@@ -0,0 +1,108 @@
+use day_08::AntennaMap;
+use proptest::prelude::*;
+
+type Antenna = (char, i64, i64);
+
+fn antenna_pairs(antennas: &[Antenna]) -> impl Iterator<Item = (&Antenna, &Antenna)> {
+    antennas.iter().flat_map(move |a| {
+        antennas
+            .iter()
+            .filter(move |b| a != *b && a.0 == b.0)
+            .map(move |b| (a, b))
+    })
+}
+
+// whether `p`, `a` and `b` lie on one line
+fn in_line(p: (i64, i64), a: (i64, i64), b: (i64, i64)) -> bool {
+    (a.0 - p.0) * (b.1 - p.1) == (a.1 - p.1) * (b.0 - p.0)
+}
+
+fn squared_distance(p: (i64, i64), q: (i64, i64)) -> i64 {
+    (p.0 - q.0).pow(2) + (p.1 - q.1).pow(2)
+}
+
+// positions in line with two antennas of a frequency, one twice as far away as the other and
+// both on the same side
+fn oracle_count_antinodes(width: i64, height: i64, antennas: &[Antenna]) -> usize {
+    let mut count = 0;
+    for y in 0..height {
+        for x in 0..width {
+            let p = (x, y);
+            if antenna_pairs(antennas).any(|(a, b)| {
+                let (a, b) = ((a.1, a.2), (b.1, b.2));
+                let same_side = 0 < (a.0 - p.0) * (b.0 - p.0) + (a.1 - p.1) * (b.1 - p.1);
+                in_line(p, a, b)
+                    && same_side
+                    && squared_distance(p, a) == 4 * squared_distance(p, b)
+            }) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+// positions exactly in line with at least two antennas of a frequency
+fn oracle_count_resonant_antinodes(width: i64, height: i64, antennas: &[Antenna]) -> usize {
+    let mut count = 0;
+    for y in 0..height {
+        for x in 0..width {
+            if antenna_pairs(antennas).any(|(a, b)| in_line((x, y), (a.1, a.2), (b.1, b.2))) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn antenna_map() -> impl Strategy<Value = Vec<Vec<char>>> {
+    (1..10usize, 1..10usize).prop_flat_map(|(width, height)| {
+        let position = prop_oneof![
+            4 => Just('.'),
+            1 => prop::sample::select(vec!['a', 'A', '0']),
+        ];
+        prop::collection::vec(prop::collection::vec(position, width), height)
+    })
+}
+
+#[test]
+fn test_resonant_antinodes() {
+    let lone_antenna: AntennaMap = "..a..\n".parse().unwrap();
+    assert_eq!(lone_antenna.count_resonant_antinodes(), 0);
+    // every position between the antennas is in line with both
+    let antennas: AntennaMap = "a...a\n".parse().unwrap();
+    assert_eq!(antennas.count_resonant_antinodes(), 5);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_oracle(rows in antenna_map()) {
+        let input: String = rows
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+        let antenna_map: AntennaMap = input.parse().unwrap();
+
+        let antennas: Vec<Antenna> = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, frequency)| **frequency != '.')
+                    .map(move |(x, frequency)| (*frequency, x as i64, y as i64))
+            })
+            .collect();
+        let (width, height) = (rows[0].len() as i64, rows.len() as i64);
+        prop_assert_eq!(
+            antenna_map.count_antinodes(),
+            oracle_count_antinodes(width, height, &antennas)
+        );
+        prop_assert_eq!(
+            antenna_map.count_resonant_antinodes(),
+            oracle_count_resonant_antinodes(width, height, &antennas)
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use day_08::AntennaMap;
+use grid::Point;
+use std::collections::HashSet;
+
+// the antenna map with `#` on the antinodes, as in the puzzle description
+fn render(input: &str, antinodes: &HashSet<Point>) -> String {
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            let row: String = line
+                .chars()
+                .enumerate()
+                .map(|(x, char)| {
+                    if antinodes.contains(&Point::new(x, y)) {
+                        '#'
+                    } else {
+                        char
+                    }
+                })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn test_antinodes() {
+    let input = include_str!("../data/test_data_1");
+    let antenna_map: AntennaMap = input.parse().unwrap();
+    insta::assert_snapshot!("antinodes", render(input, &antenna_map.antinodes()));
+    insta::assert_snapshot!(
+        "resonant_antinodes",
+        render(input, &antenna_map.resonant_antinodes())
+    );
+}
@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_09::{puzzle01, puzzle02};
+
+fn bench_puzzles(c: &mut Criterion) {
+    c.bench_function("day 09 part 1", |b| {
+        b.iter(|| puzzle01("data/data_1").unwrap())
+    });
+    c.bench_function("day 09 part 2", |b| {
+        b.iter(|| puzzle02("data/data_1").unwrap())
+    });
+}
+
+// solving takes too long for the default of 100 samples
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_puzzles
+}
+criterion_main!(benches);
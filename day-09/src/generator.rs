@@ -0,0 +1,19 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A disk map of `size` digits, files take 1 to 9 blocks and free spaces 0 to 9.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut input: String = (0..size)
+        .map(|index| {
+            let length = if index % 2 == 0 {
+                rng.gen_range(1..=9)
+            } else {
+                rng.gen_range(0..=9)
+            };
+            char::from(b'0' + length)
+        })
+        .collect();
+    input.push('\n');
+    input
+}
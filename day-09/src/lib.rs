@@ -0,0 +1,312 @@
+pub mod generator;
+pub mod validator;
+
+use anyhow::bail;
+use aoc_core::{Answer, Location, Problem, Solution};
+use std::{
+    fs::File,
+    io::{self, Read},
+    iter,
+    path::Path,
+    str::FromStr,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum DiskMapError {
+    #[error("invalid digit {char:?} at {location}")]
+    InvalidDigit { location: Location, char: char },
+}
+
+/// The dense disk map: the lengths of files and free spaces, alternating and starting with a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskMap {
+    lengths: Vec<u32>,
+}
+
+impl FromStr for DiskMap {
+    type Err = DiskMapError;
+
+    // a single line of digits, with or without a final newline
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lengths = Vec::new();
+        let line = s.trim_end();
+        for (index, c) in line.char_indices() {
+            lengths.push(c.to_digit(10).ok_or_else(|| DiskMapError::InvalidDigit {
+                location: Location::of(s, &s[index..]),
+                char: c,
+            })?);
+        }
+        Ok(DiskMap { lengths })
+    }
+}
+
+impl DiskMap {
+    pub fn lengths(&self) -> &[u32] {
+        &self.lengths
+    }
+
+    /// The file id of every block after moving single blocks from the end into the leftmost
+    /// free space, `None` for free blocks.
+    pub fn compact_blocks(&self) -> Vec<Option<usize>> {
+        compact_blocks(&self.lengths)
+    }
+
+    /// Like [`DiskMap::compact_blocks`], moving whole files into the leftmost free space that
+    /// fits them.
+    pub fn compact_files(&self) -> anyhow::Result<Vec<Option<usize>>> {
+        compact_files(&self.lengths)
+    }
+
+    /// The checksum after moving single blocks from the end into the leftmost free space.
+    pub fn compact_blocks_checksum(&self) -> usize {
+        checksum(&self.compact_blocks())
+    }
+
+    /// The checksum after moving whole files into the leftmost free space that fits them.
+    pub fn compact_files_checksum(&self) -> anyhow::Result<usize> {
+        Ok(checksum(&self.compact_files()?))
+    }
+}
+
+// the sum of the block positions multiplied by their file ids
+fn checksum(disk: &[Option<usize>]) -> usize {
+    disk.iter()
+        .enumerate()
+        .filter_map(|(i, val)| val.map(|v| i * v))
+        .sum()
+}
+
+fn compact_blocks(disk_map: &[u32]) -> Vec<Option<usize>> {
+    let mut file_id = 0;
+    let mut is_file = true;
+    let mut disk: Vec<Option<usize>> = Vec::new();
+    for &count in disk_map {
+        if is_file {
+            for _ in 0..count {
+                disk.push(Some(file_id));
+            }
+            file_id += 1;
+            is_file = false
+        } else {
+            for _ in 0..count {
+                disk.push(None);
+            }
+            is_file = true
+        }
+    }
+
+    for i in 0..disk.len() {
+        match disk.get(i) {
+            Some(val) => {
+                if val.is_none() {
+                    for j in (i + 1..disk.len()).rev() {
+                        if disk[j].is_some() {
+                            disk.swap(i, j);
+                            break;
+                        }
+                    }
+                }
+            }
+            None => break,
+        }
+    }
+
+    disk
+}
+
+/// A run of blocks on the disk during the file compaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Empty(u32),          // length
+    Moved(usize, u32),   // (file_id, length)
+    Unmoved(usize, u32), // (file_id, length)
+}
+
+fn compact_files(disk_map: &[u32]) -> anyhow::Result<Vec<Option<usize>>> {
+    let mut file_id = 0;
+    let mut is_file = true;
+    let mut disk: Vec<Block> = Vec::new();
+    for &count in disk_map {
+        if is_file {
+            if 0 < count {
+                disk.push(Block::Unmoved(file_id, count));
+            }
+            file_id += 1;
+            is_file = false
+        } else {
+            if 0 < count {
+                disk.push(Block::Empty(count));
+            }
+            is_file = true
+        }
+    }
+
+    let mut new_disk = Vec::new();
+    while let Some(block) = disk.pop() {
+        match block {
+            Block::Unmoved(file_id, length) => {
+                let index_free_block = {
+                    let mut result = None;
+                    for (index, block) in disk.iter().enumerate() {
+                        match block {
+                            Block::Empty(empty_len) => {
+                                if &length <= empty_len {
+                                    result = Some(index);
+                                    break;
+                                }
+                            }
+                            _ => continue,
+                        }
+                    }
+                    result
+                };
+                match index_free_block {
+                    Some(index) => {
+                        let empty_block = disk.remove(index);
+                        match empty_block {
+                            Block::Empty(empty_len) => {
+                                let new_empty_len = empty_len - length;
+                                if 0 < new_empty_len {
+                                    disk.insert(index, Block::Empty(new_empty_len));
+                                }
+                                disk.insert(index, Block::Moved(file_id, length));
+                                disk.push(Block::Empty(length));
+                            }
+                            _ => bail!("There should be an empty element on that position"),
+                        }
+                    }
+                    None => disk.push(Block::Moved(file_id, length)),
+                }
+            }
+            Block::Moved(file_id, length) => {
+                new_disk.extend(iter::repeat_n(Some(file_id), length as usize));
+            }
+            Block::Empty(length) => {
+                new_disk.extend(iter::repeat_n(None, length as usize));
+            }
+        }
+    }
+
+    new_disk.reverse();
+    Ok(new_disk)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = DiskMap;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(disk_map: &DiskMap, _: &()) -> anyhow::Result<Answer> {
+        Ok(disk_map.compact_blocks_checksum().into())
+    }
+
+    fn part2(disk_map: &DiskMap, _: &()) -> anyhow::Result<Answer> {
+        Ok(disk_map.compact_files_checksum()?.into())
+    }
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<usize>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<usize>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<usize> {
+    let disk_map: DiskMap = input.parse()?;
+    Ok(disk_map.compact_blocks_checksum())
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<usize>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<usize>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<usize> {
+    let disk_map: DiskMap = input.parse()?;
+    disk_map.compact_files_checksum()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 1928)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 1928)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1").unwrap(), 2858)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use crate::{DiskMap, DiskMapError};
+        use aoc_core::Location;
+
+        assert_eq!(
+            "12345".parse::<DiskMap>().unwrap().lengths(),
+            [1, 2, 3, 4, 5]
+        );
+        assert_eq!("12345\r\n".parse::<DiskMap>(), "12345\n".parse::<DiskMap>());
+        assert_eq!(
+            "123-5\n".parse::<DiskMap>(),
+            Err(DiskMapError::InvalidDigit {
+                location: Location::new(1, 4),
+                char: '-'
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("12a45"),
+            [Problem::at_line(1, "invalid digit 'a' in column 3")]
+        );
+        assert_eq!(validate("12345"), []);
+        assert_eq!(validate("12345\r\n\n"), []);
+    }
+}
@@ -0,0 +1,26 @@
+use aoc_core::Problem;
+
+/// A single line of digits, a final newline is optional.
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    for (index, line) in input.trim_end().lines().enumerate() {
+        if 0 < index {
+            problems.push(Problem::at_line(
+                index + 1,
+                "the disk map has more than one line",
+            ));
+        }
+        for (column, char) in line.chars().enumerate() {
+            if !char.is_ascii_digit() {
+                problems.push(Problem::at_line(
+                    index + 1,
+                    format!("invalid digit {:?} in column {}", char, column + 1),
+                ));
+            }
+        }
+    }
+    if input.trim().is_empty() {
+        problems.push(Problem::new("the disk map is empty"));
+    }
+    problems
+}
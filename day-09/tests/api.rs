@@ -0,0 +1,11 @@
+use day_09::DiskMap;
+
+#[test]
+fn test_disk_map() {
+    let disk_map: DiskMap = "12345\n".parse().unwrap();
+    assert_eq!(disk_map.lengths(), [1, 2, 3, 4, 5]);
+
+    let disk_map: DiskMap = include_str!("../data/test_data_1").parse().unwrap();
+    assert_eq!(disk_map.compact_blocks_checksum(), 1928);
+    assert_eq!(disk_map.compact_files_checksum().unwrap(), 2858);
+}
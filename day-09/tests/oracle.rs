@@ -0,0 +1,84 @@
+use day_09::DiskMap;
+use proptest::prelude::*;
+
+// the file id of every block, `None` for free blocks
+fn blocks(lengths: &[u32]) -> Vec<Option<usize>> {
+    let mut blocks = Vec::new();
+    for (index, length) in lengths.iter().enumerate() {
+        let block = (index % 2 == 0).then_some(index / 2);
+        blocks.extend(std::iter::repeat_n(block, *length as usize));
+    }
+    blocks
+}
+
+fn checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(position, id)| position * id.unwrap_or(0))
+        .sum()
+}
+
+// moves the last file block into the first free block until no gaps are left
+fn oracle_compact_blocks(lengths: &[u32]) -> usize {
+    let mut blocks = blocks(lengths);
+    while let Some(free) = blocks.iter().position(Option::is_none) {
+        match blocks.iter().rposition(Option::is_some) {
+            Some(last) if free < last => blocks.swap(free, last),
+            _ => break,
+        }
+    }
+    checksum(&blocks)
+}
+
+// moves every file once, by decreasing id, into the leftmost free span before it that fits
+fn oracle_compact_files(lengths: &[u32]) -> usize {
+    let mut blocks = blocks(lengths);
+    for id in (0..lengths.len().div_ceil(2)).rev() {
+        let start = blocks.iter().position(|block| *block == Some(id)).unwrap();
+        let length = lengths[2 * id] as usize;
+        let free_start = (0..start).find(|free_start| {
+            blocks[*free_start..*free_start + length]
+                .iter()
+                .all(Option::is_none)
+        });
+        if let Some(free_start) = free_start {
+            for offset in 0..length {
+                blocks.swap(free_start + offset, start + offset);
+            }
+        }
+    }
+    checksum(&blocks)
+}
+
+// files of 1 to 9 blocks separated by 0 to 9 free blocks
+fn disk_map() -> impl Strategy<Value = Vec<u32>> {
+    prop::collection::vec((1..=9u32, 0..=9u32), 1..10).prop_flat_map(|pairs| {
+        let lengths: Vec<u32> = pairs
+            .iter()
+            .flat_map(|(file, free)| [*file, *free])
+            .collect();
+        // with or without free space after the last file
+        (Just(lengths), any::<bool>()).prop_map(|(mut lengths, trailing_free)| {
+            if !trailing_free {
+                lengths.pop();
+            }
+            lengths
+        })
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_oracle(lengths in disk_map()) {
+        let input: String = lengths.iter().map(u32::to_string).collect::<String>() + "\n";
+        let disk_map: DiskMap = input.parse().unwrap();
+        prop_assert_eq!(disk_map.compact_blocks_checksum(), oracle_compact_blocks(&lengths));
+        prop_assert_eq!(
+            disk_map.compact_files_checksum().unwrap(),
+            oracle_compact_files(&lengths)
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use day_09::DiskMap;
+
+// the file ids of the blocks and `.` for free blocks, as in the puzzle description
+fn layout(disk: &[Option<usize>]) -> String {
+    disk.iter()
+        .map(|block| block.map_or(".".to_string(), |id| id.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_compacted_disk() {
+    let disk_map: DiskMap = include_str!("../data/test_data_1").parse().unwrap();
+    insta::assert_snapshot!("compact_blocks", layout(&disk_map.compact_blocks()));
+    insta::assert_snapshot!("compact_files", layout(&disk_map.compact_files().unwrap()));
+}
@@ -0,0 +1,43 @@
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A `size` x `size` topographic map of random heights, crossed by `size` hiking trails that
+/// climb from 0 to 9 in single steps.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut heights: Vec<Vec<u8>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.gen_range(0..=9)).collect())
+        .collect();
+    for _ in 0..size {
+        let (mut x, mut y) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        for height in 0..=9 {
+            heights[y][x] = height;
+            let mut steps = Vec::new();
+            if 0 < x {
+                steps.push((x - 1, y));
+            }
+            if x + 1 < size {
+                steps.push((x + 1, y));
+            }
+            if 0 < y {
+                steps.push((x, y - 1));
+            }
+            if y + 1 < size {
+                steps.push((x, y + 1));
+            }
+            match steps.choose(&mut rng) {
+                Some(step) => (x, y) = *step,
+                None => break,
+            }
+        }
+    }
+    heights
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|height| char::from(b'0' + height))
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
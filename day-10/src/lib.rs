@@ -0,0 +1,248 @@
+pub mod generator;
+pub mod validator;
+
+use anyhow::{bail, Context};
+use aoc_core::{Answer, Problem, Solution};
+pub use grid::Point;
+use grid::{Grid, GridError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{self, Read},
+    path::Path,
+    str::FromStr,
+};
+
+/// The height of every position of the map together with its trailheads (height 0).
+#[derive(Clone)]
+pub struct TopographicMap {
+    map: Grid<u32>,
+    trailheads: Vec<Point>,
+}
+
+impl FromStr for TopographicMap {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(s, |c| c.to_digit(10))?;
+        let trailheads = map.positions(|value| *value == 0).collect();
+
+        Ok(TopographicMap { map, trailheads })
+    }
+}
+
+impl TopographicMap {
+    pub fn height(&self, point: &Point) -> Option<u32> {
+        self.map.get(*point).copied()
+    }
+
+    pub fn trailheads(&self) -> &[Point] {
+        &self.trailheads
+    }
+
+    fn get_path_valid_neighbours(&self, point: &Point, path: &[Point]) -> Vec<Point> {
+        self.map
+            .neighbours4(*point)
+            .filter(|next_point| !path.contains(next_point))
+            .filter(|next_point| self.is_valid_step(point, next_point))
+            .collect()
+    }
+
+    fn get_unvisited_valid_neighbours(&self, point: Point, visited: &HashSet<Point>) -> Vec<Point> {
+        self.map
+            .neighbours4(point)
+            .filter(|next_point| !visited.contains(next_point))
+            .filter(|next_point| self.is_valid_step(&point, next_point))
+            .collect()
+    }
+
+    fn is_valid_step(&self, point: &Point, next_point: &Point) -> bool {
+        let Some(point_val) = self.map.get(*point) else {
+            return false;
+        };
+        let Some(next_point_val) = self.map.get(*next_point) else {
+            return false;
+        };
+        *point_val + 1 == *next_point_val
+    }
+
+    fn is_end_point(&self, point: &Point) -> anyhow::Result<bool> {
+        match self.map.get(*point) {
+            Some(val) => {
+                if *val == 9 {
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            None => bail!("Point does not exist"),
+        }
+    }
+
+    // visits a point. Returns true when the point is an end point (9), false if not. Error if the point was visited already or if it does not exist (This should not happen).
+    fn visit_point(&self, point: Point, visited: &mut HashSet<Point>) -> anyhow::Result<bool> {
+        match visited.contains(&point) {
+            true => bail!("Point was visited already. There seems to be an error when getting unvisited neighbours"),
+            false => {
+                visited.insert(point);
+                self.is_end_point(&point)
+            }
+        }
+    }
+
+    // the number of distinct end points (9) reachable from a trailhead
+    fn trailhead_score(&self, starting_point: Point) -> anyhow::Result<usize> {
+        let mut trailhead_score = 0;
+        let mut visited = HashSet::new();
+        let mut point_vec = vec![starting_point];
+        while let Some(point) = point_vec.pop() {
+            if self.visit_point(point, &mut visited)? {
+                // point is an endpoint
+                trailhead_score += 1;
+            }
+            point_vec.append(&mut self.get_unvisited_valid_neighbours(point, &visited));
+        }
+        Ok(trailhead_score)
+    }
+
+    // the number of distinct hiking trails starting at a trailhead
+    fn trailhead_rating(&self, starting_point: Point) -> anyhow::Result<usize> {
+        let mut trailhead_score = 0;
+        let mut path_vec = vec![vec![starting_point]];
+        while let Some(path) = path_vec.pop() {
+            let last_point = path.last().context("Path is empty but should not be")?;
+            if self.is_end_point(last_point)? {
+                trailhead_score += 1;
+            } else {
+                for point in self.get_path_valid_neighbours(last_point, &path) {
+                    let mut new_path = path.clone();
+                    new_path.push(point);
+                    path_vec.push(new_path);
+                }
+            }
+        }
+        Ok(trailhead_score)
+    }
+
+    /// The number of distinct end points (9) reachable from each trailhead, summed up.
+    pub fn sum_of_trailhead_scores(&self) -> anyhow::Result<usize> {
+        #[cfg(feature = "parallel")]
+        let trailheads = self.trailheads.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let trailheads = self.trailheads.iter();
+        trailheads
+            .map(|starting_point| self.trailhead_score(*starting_point))
+            .sum()
+    }
+
+    /// The number of distinct hiking trails starting at each trailhead, summed up.
+    pub fn sum_of_trailhead_ratings(&self) -> anyhow::Result<usize> {
+        #[cfg(feature = "parallel")]
+        let trailheads = self.trailheads.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let trailheads = self.trailheads.iter();
+        trailheads
+            .map(|starting_point| self.trailhead_rating(*starting_point))
+            .sum()
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = TopographicMap;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(map: &TopographicMap, _: &()) -> anyhow::Result<Answer> {
+        Ok(map.sum_of_trailhead_scores()?.into())
+    }
+
+    fn part2(map: &TopographicMap, _: &()) -> anyhow::Result<Answer> {
+        Ok(map.sum_of_trailhead_ratings()?.into())
+    }
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<usize>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<usize>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<usize> {
+    let map: TopographicMap = input.parse()?;
+    map.sum_of_trailhead_scores()
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<usize>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<usize>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<usize> {
+    let map: TopographicMap = input.parse()?;
+    map.sum_of_trailhead_ratings()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 36)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 36)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1").unwrap(), 81)
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("0123\n1.34\n"),
+            [Problem::at_line(2, "invalid character '.' in column 2")]
+        );
+    }
+}
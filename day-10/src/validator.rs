@@ -0,0 +1,6 @@
+use aoc_core::{check_grid, Problem};
+
+/// A rectangular map of heights from 0 to 9.
+pub fn validate(input: &str) -> Vec<Problem> {
+    check_grid(input, |char| char.is_ascii_digit())
+}
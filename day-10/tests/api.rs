@@ -0,0 +1,10 @@
+use day_10::{Point, TopographicMap};
+
+#[test]
+fn test_topographic_map() {
+    let map: TopographicMap = include_str!("../data/test_data_1").parse().unwrap();
+    assert_eq!(map.trailheads().len(), 9);
+    assert_eq!(map.height(&Point::new(2, 0)), Some(0));
+    assert_eq!(map.sum_of_trailhead_scores().unwrap(), 36);
+    assert_eq!(map.sum_of_trailhead_ratings().unwrap(), 81);
+}
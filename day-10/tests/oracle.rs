@@ -0,0 +1,86 @@
+use day_10::TopographicMap;
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+// the neighbours one higher than the position
+fn uphill(heights: &[Vec<u32>], (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+    let mut neighbours = vec![(x + 1, y), (x, y + 1)];
+    if 0 < x {
+        neighbours.push((x - 1, y));
+    }
+    if 0 < y {
+        neighbours.push((x, y - 1));
+    }
+    neighbours
+        .into_iter()
+        .filter(|(next_x, next_y)| {
+            heights
+                .get(*next_y)
+                .and_then(|row| row.get(*next_x))
+                .is_some_and(|height| *height == heights[y][x] + 1)
+        })
+        .collect()
+}
+
+// every hiking trail from the position, as the list of its positions
+fn trails(heights: &[Vec<u32>], position: (usize, usize)) -> Vec<Vec<(usize, usize)>> {
+    if heights[position.1][position.0] == 9 {
+        return vec![vec![position]];
+    }
+    let mut trails_from_here = Vec::new();
+    for next in uphill(heights, position) {
+        for mut trail in trails(heights, next) {
+            trail.insert(0, position);
+            trails_from_here.push(trail);
+        }
+    }
+    trails_from_here
+}
+
+fn oracle_scores_and_ratings(heights: &[Vec<u32>]) -> (usize, usize) {
+    let mut scores = 0;
+    let mut ratings = 0;
+    for y in 0..heights.len() {
+        for x in 0..heights[0].len() {
+            if heights[y][x] == 0 {
+                let trails = trails(heights, (x, y));
+                let ends: HashSet<_> = trails.iter().filter_map(|trail| trail.last()).collect();
+                scores += ends.len();
+                ratings += trails.len();
+            }
+        }
+    }
+    (scores, ratings)
+}
+
+// a slope rising by one per step right or down, partly replaced by random heights
+fn topographic_map() -> impl Strategy<Value = Vec<Vec<u32>>> {
+    (1..12usize, 1..12usize, 0..10u32).prop_flat_map(|(width, height, base)| {
+        let cells = prop::collection::vec(prop::option::weighted(0.3, 0..10u32), width * height);
+        cells.prop_map(move |cells| {
+            (0..height)
+                .map(|y| {
+                    (0..width)
+                        .map(|x| cells[y * width + x].unwrap_or((base + (x + y) as u32) % 10))
+                        .collect()
+                })
+                .collect()
+        })
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_oracle(heights in topographic_map()) {
+        let input: String = heights
+            .iter()
+            .map(|row| row.iter().map(u32::to_string).collect::<String>() + "\n")
+            .collect();
+        let topographic_map: TopographicMap = input.parse().unwrap();
+        let (scores, ratings) = oracle_scores_and_ratings(&heights);
+        prop_assert_eq!(topographic_map.sum_of_trailhead_scores().unwrap(), scores);
+        prop_assert_eq!(topographic_map.sum_of_trailhead_ratings().unwrap(), ratings);
+    }
+}
@@ -0,0 +1,18 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A line of `size` stones engraved with numbers below ten million, a few of them zeros.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let stones: Vec<_> = (0..size)
+        .map(|_| {
+            if rng.gen_ratio(1, 10) {
+                0
+            } else {
+                rng.gen_range(1..10_000_000u64)
+            }
+        })
+        .map(|number| number.to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
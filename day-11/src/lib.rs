@@ -0,0 +1,327 @@
+pub mod generator;
+pub mod validator;
+
+use anyhow::{bail, Context};
+use aoc_core::{Answer, Location, Parameters, Problem, Solution};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, Read},
+    num::ParseIntError,
+    path::Path,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StoneError {
+    #[error("invalid stone number {text:?} at {location}")]
+    InvalidNumber {
+        location: Location,
+        text: String,
+        source: ParseIntError,
+    },
+    #[error("the stone number {number} times 2024 does not fit into 64 bits")]
+    NumberOverflow { number: u64 },
+    #[error("the number of stones after {blinks} blinks does not fit into 64 bits")]
+    CountOverflow { blinks: usize },
+}
+
+/// Parses the space separated numbers engraved on the stones.
+pub fn parse_stones(input: &str) -> Result<Vec<u64>, StoneError> {
+    let mut stone_numbers = Vec::new();
+    for line in input.lines() {
+        for line_part in line.split(" ") {
+            let number = line_part.trim();
+            stone_numbers.push(number.parse().map_err(|source| StoneError::InvalidNumber {
+                location: Location::of(input, number),
+                text: number.to_string(),
+                source,
+            })?);
+        }
+    }
+    Ok(stone_numbers)
+}
+
+/// A stone with its engraved number and the blink it was created in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stone {
+    number: u64,
+    blinks: usize,
+}
+
+impl Stone {
+    pub fn new(number: u64) -> Self {
+        Stone { number, blinks: 1 }
+    }
+
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+
+    pub fn blinks(&self) -> usize {
+        self.blinks
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.number == 0
+    }
+
+    /// The two stones a stone with an even number of digits splits into.
+    pub fn try_get_stone_halves(&self) -> Option<Vec<Stone>> {
+        let number_of_digits = self.number.checked_ilog10().unwrap_or(0) + 1;
+        if number_of_digits.is_multiple_of(2) {
+            let divisor = u64::pow(10, number_of_digits / 2);
+            let first_half = self.number / divisor;
+            let second_half = self.number % divisor;
+            Some(vec![
+                Stone {
+                    number: first_half,
+                    blinks: self.blinks + 1,
+                },
+                Stone {
+                    number: second_half,
+                    blinks: self.blinks + 1,
+                },
+            ])
+        } else {
+            None
+        }
+    }
+}
+
+// the number of a stone with an odd number of digits after a blink
+fn multiply_number(number: u64) -> Result<u64, StoneError> {
+    number
+        .checked_mul(2024)
+        .ok_or(StoneError::NumberOverflow { number })
+}
+
+// adds `count` stones, `blinks` is only reported if the count overflows
+fn add_stones(
+    stone_map: &mut HashMap<u64, u64>,
+    stone_number: u64,
+    count: u64,
+    blinks: usize,
+) -> Result<(), StoneError> {
+    let stones = stone_map.entry(stone_number).or_insert(0);
+    *stones = stones
+        .checked_add(count)
+        .ok_or(StoneError::CountOverflow { blinks })?;
+    Ok(())
+}
+
+/// Simulates every single stone, the line of stones grows exponentially.
+pub fn count_stones(stone_numbers: &[u64], number_of_blinks: usize) -> Result<usize, StoneError> {
+    let mut stone_line: Vec<Stone> = stone_numbers
+        .iter()
+        .map(|number| Stone::new(*number))
+        .collect();
+
+    for i in 1..=number_of_blinks {
+        let mut new_stone_line = Vec::with_capacity(stone_line.len());
+        for stone in stone_line {
+            if stone.is_zero() {
+                new_stone_line.push(Stone::new(1));
+            } else if let Some(stone_halves) = stone.try_get_stone_halves() {
+                new_stone_line.extend(stone_halves);
+            } else {
+                new_stone_line.push(Stone::new(multiply_number(stone.number)?));
+            }
+        }
+        stone_line = new_stone_line;
+        tracing::debug!(blinks = i, stones = stone_line.len(), "blinked");
+    }
+
+    Ok(stone_line.len())
+}
+
+/// Counts the stones per engraved number, the order of the stones does not matter.
+pub fn count_stones_by_number(
+    stone_numbers: &[u64],
+    number_of_blinks: usize,
+) -> Result<u64, StoneError> {
+    let mut stone_map: HashMap<u64, u64> = HashMap::new();
+
+    for stone_number in stone_numbers {
+        add_stones(&mut stone_map, *stone_number, 1, 0)?;
+    }
+    let mut number_of_stones = stone_numbers.len() as u64;
+
+    for i in 1..=number_of_blinks {
+        let mut new_stone_map = HashMap::new();
+        for (stone_number, count) in stone_map {
+            if stone_number == 0 {
+                add_stones(&mut new_stone_map, stone_number + 1, count, i)?;
+            } else {
+                let number_of_digits = stone_number.checked_ilog10().unwrap_or(0) + 1;
+                if number_of_digits.is_multiple_of(2) {
+                    let divisor = u64::pow(10, number_of_digits / 2);
+                    let first_half = stone_number / divisor;
+                    let second_half = stone_number % divisor;
+                    add_stones(&mut new_stone_map, first_half, count, i)?;
+                    add_stones(&mut new_stone_map, second_half, count, i)?;
+                } else {
+                    add_stones(&mut new_stone_map, multiply_number(stone_number)?, count, i)?;
+                }
+            }
+        }
+        stone_map = new_stone_map;
+        number_of_stones = stone_map
+            .values()
+            .try_fold(0u64, |sum, count| sum.checked_add(*count))
+            .ok_or(StoneError::CountOverflow { blinks: i })?;
+        tracing::debug!(
+            blinks = i,
+            stones = number_of_stones,
+            distinct_numbers = stone_map.len(),
+            "blinked"
+        );
+    }
+
+    Ok(number_of_stones)
+}
+
+pub struct Day11;
+
+/// The number of blinks of both parts.
+pub struct Blinks {
+    part1: usize,
+    part2: usize,
+}
+
+impl Default for Blinks {
+    fn default() -> Self {
+        Blinks {
+            part1: 25,
+            part2: 75,
+        }
+    }
+}
+
+impl Parameters for Blinks {
+    fn set(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name {
+            "blinks" => {
+                let number_of_blinks = value
+                    .parse()
+                    .with_context(|| format!("invalid number of blinks `{}`", value))?;
+                self.part1 = number_of_blinks;
+                self.part2 = number_of_blinks;
+            }
+            _ => bail!("unknown parameter `{}`, expected `blinks`", name),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+    type Params = Blinks;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_stones(input)?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(stone_numbers: &Self::Input, blinks: &Blinks) -> anyhow::Result<Answer> {
+        Ok(count_stones(stone_numbers, blinks.part1)?.into())
+    }
+
+    fn part2(stone_numbers: &Self::Input, blinks: &Blinks) -> anyhow::Result<Answer> {
+        Ok(count_stones_by_number(stone_numbers, blinks.part2)?.into())
+    }
+}
+
+pub fn puzzle01<P>(filename: P, number_of_blinks: usize) -> anyhow::Result<usize>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?, number_of_blinks)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R, number_of_blinks: usize) -> anyhow::Result<usize>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?, number_of_blinks)
+}
+
+pub fn puzzle01_from_str(input: &str, number_of_blinks: usize) -> anyhow::Result<usize> {
+    let stone_numbers = parse_stones(input)?;
+    Ok(count_stones(&stone_numbers, number_of_blinks)?)
+}
+
+pub fn puzzle02<P>(filename: P, number_of_blinks: usize) -> anyhow::Result<u64>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?, number_of_blinks)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R, number_of_blinks: usize) -> anyhow::Result<u64>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?, number_of_blinks)
+}
+
+pub fn puzzle02_from_str(input: &str, number_of_blinks: usize) -> anyhow::Result<u64> {
+    let stone_numbers = parse_stones(input)?;
+    Ok(count_stones_by_number(&stone_numbers, number_of_blinks)?)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1", 25).unwrap(), 55312)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input, 25).unwrap(), 55312)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1", 25).unwrap(), 55312)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use crate::{parse_stones, StoneError};
+        use aoc_core::Location;
+
+        assert_eq!(parse_stones("125 17\n").unwrap(), [125, 17]);
+        let error = parse_stones("125 1x7\n").unwrap_err();
+        assert!(matches!(
+            error,
+            StoneError::InvalidNumber { location, .. } if location == Location::new(1, 5)
+        ));
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("125  17\n"),
+            [Problem::at_line(
+                1,
+                "the stones are not separated by single spaces"
+            )]
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_core::{check_numbers, Problem};
+
+/// The engraved numbers of the stones, separated by single spaces.
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        problems.extend(check_numbers::<u64>(index + 1, line, "stone number"));
+        if line.split(' ').any(str::is_empty) {
+            problems.push(Problem::at_line(
+                index + 1,
+                "the stones are not separated by single spaces",
+            ));
+        }
+    }
+    if input.trim().is_empty() {
+        problems.push(Problem::new("there are no stones"));
+    }
+    problems
+}
@@ -0,0 +1,15 @@
+use day_11::{count_stones, count_stones_by_number, Stone};
+
+#[test]
+fn test_stones() {
+    let stone = Stone::new(1000);
+    let halves = stone.try_get_stone_halves().unwrap();
+    assert_eq!(halves[0].number(), 10);
+    assert_eq!(halves[1].number(), 0);
+    assert_eq!(halves[1].blinks(), 2);
+    assert!(halves[1].is_zero());
+    assert_eq!(Stone::new(123).try_get_stone_halves(), None);
+
+    assert_eq!(count_stones(&[125, 17], 6).unwrap(), 22);
+    assert_eq!(count_stones_by_number(&[125, 17], 6).unwrap(), 22);
+}
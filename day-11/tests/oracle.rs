@@ -0,0 +1,69 @@
+use day_11::{count_stones, count_stones_by_number, StoneError};
+use proptest::prelude::*;
+
+// applies the rules to every stone of the line, `None` if a number overflows
+fn blink(stones: &[u64]) -> Option<Vec<u64>> {
+    let mut next = Vec::new();
+    for stone in stones {
+        let digits = stone.to_string();
+        if *stone == 0 {
+            next.push(1);
+        } else if digits.len() % 2 == 0 {
+            let (left, right) = digits.split_at(digits.len() / 2);
+            next.push(left.parse().unwrap());
+            next.push(right.parse().unwrap());
+        } else {
+            next.push(stone.checked_mul(2024)?);
+        }
+    }
+    Some(next)
+}
+
+fn oracle_count_stones(stones: &[u64], blinks: usize) -> Option<usize> {
+    let mut stones = stones.to_vec();
+    for _ in 0..blinks {
+        stones = blink(&stones)?;
+    }
+    Some(stones.len())
+}
+
+fn stone() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        Just(0u64),
+        0..1000u64,
+        0..10_000_000u64,
+        (u64::MAX / 2024 - 1000)..=u64::MAX
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+
+    #[test]
+    fn test_oracle(stones in prop::collection::vec(stone(), 1..5), blinks in 0..12usize) {
+        let expected = oracle_count_stones(&stones, blinks);
+        prop_assert_eq!(count_stones(&stones, blinks).ok(), expected);
+        prop_assert_eq!(
+            count_stones_by_number(&stones, blinks).ok(),
+            expected.map(|expected| expected as u64)
+        );
+    }
+}
+
+#[test]
+fn test_overflows() {
+    // 19 digits, the number times 2024 has 23 digits
+    let stones = [9_999_999_999_999_999_999];
+    assert!(matches!(
+        count_stones(&stones, 1),
+        Err(StoneError::NumberOverflow { .. })
+    ));
+    assert!(matches!(
+        count_stones_by_number(&stones, 1),
+        Err(StoneError::NumberOverflow { .. })
+    ));
+    assert_eq!(
+        count_stones_by_number(&[0], 500).unwrap_err().to_string(),
+        "the number of stones after 108 blinks does not fit into 64 bits"
+    );
+}
@@ -0,0 +1,25 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A `size` x `size` map of garden plots, most plots continue the region on their left or
+/// above, so the regions grow to different sizes and shapes.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut plots: Vec<Vec<char>> = Vec::with_capacity(size);
+    for y in 0..size {
+        let mut row: Vec<char> = Vec::with_capacity(size);
+        for x in 0..size {
+            let plant = match rng.gen_range(0..10) {
+                0..=3 if 0 < x => row[x - 1],
+                4..=7 if 0 < y => plots[y - 1][x],
+                _ => char::from(rng.gen_range(b'A'..=b'Z')),
+            };
+            row.push(plant);
+        }
+        plots.push(row);
+    }
+    plots
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
@@ -0,0 +1,187 @@
+pub mod generator;
+pub mod validator;
+
+use aoc_core::{Answer, Problem, Solution};
+pub use grid::Point;
+use grid::{Grid, Offset};
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+/// Splits the garden map into its regions (connected points with the same plant).
+pub fn get_regions(garden_map: &Grid<char>) -> Vec<HashSet<Point>> {
+    let mut assigned = Grid::new(garden_map.width(), garden_map.height(), false);
+    let mut regions = Vec::new();
+    for (start_point, symbol) in garden_map.iter() {
+        if assigned[start_point] {
+            continue;
+        }
+        assigned[start_point] = true;
+        let mut region = HashSet::from([start_point]);
+        let mut neighbours: Vec<Point> = garden_map.neighbours4(start_point).collect();
+        while let Some(neighbour) = neighbours.pop() {
+            if !assigned[neighbour] && garden_map[neighbour] == *symbol {
+                // neighbour is in the same garden -> add it and search in its neighbourhood
+                neighbours.extend(garden_map.neighbours4(neighbour));
+                // mark the neighbour making sure to only search it once
+                assigned[neighbour] = true;
+                region.insert(neighbour);
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+fn is_in_region(region: &HashSet<Point>, point: Option<Point>) -> bool {
+    point.is_some_and(|point| region.contains(&point))
+}
+
+pub fn get_perimeter(region: &HashSet<Point>) -> u64 {
+    region
+        .iter()
+        .map(|point| {
+            Offset::NEIGHBOURS_4
+                .into_iter()
+                .filter(|offset| !is_in_region(region, point.checked_add(*offset)))
+                .count() as u64
+        })
+        .sum()
+}
+
+/// The number of sides of a region, which equals its number of corners.
+pub fn get_number_of_sides(region: &HashSet<Point>) -> u64 {
+    region
+        .iter()
+        .map(|point| {
+            Offset::DIAGONALS
+                .into_iter()
+                .filter(|diagonal| {
+                    let horizontal =
+                        is_in_region(region, point.checked_add(Offset::new(diagonal.dx, 0)));
+                    let vertical =
+                        is_in_region(region, point.checked_add(Offset::new(0, diagonal.dy)));
+                    let diagonal = is_in_region(region, point.checked_add(*diagonal));
+                    // outer corner or inner corner
+                    (!horizontal && !vertical) || (horizontal && vertical && !diagonal)
+                })
+                .count() as u64
+        })
+        .sum()
+}
+
+pub fn total_fence_price(garden_map: &Grid<char>) -> u64 {
+    get_regions(garden_map)
+        .iter()
+        .map(|region| region.len() as u64 * get_perimeter(region))
+        .sum()
+}
+
+pub fn total_bulk_fence_price(garden_map: &Grid<char>) -> u64 {
+    get_regions(garden_map)
+        .iter()
+        .map(|region| region.len() as u64 * get_number_of_sides(region))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(Grid::parse(input, Some)?)
+    }
+
+    fn generate(seed: u64, size: usize) -> String {
+        generator::generate(seed, size)
+    }
+
+    fn validate(input: &str) -> Vec<Problem> {
+        validator::validate(input)
+    }
+
+    fn part1(garden_map: &Self::Input, _: &()) -> anyhow::Result<Answer> {
+        Ok(total_fence_price(garden_map).into())
+    }
+
+    fn part2(garden_map: &Self::Input, _: &()) -> anyhow::Result<Answer> {
+        Ok(total_bulk_fence_price(garden_map).into())
+    }
+}
+
+pub fn puzzle01<P>(filename: P) -> anyhow::Result<u64>
+where
+    P: AsRef<Path>,
+{
+    puzzle01_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle01_from_reader<R>(reader: R) -> anyhow::Result<u64>
+where
+    R: Read,
+{
+    puzzle01_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle01_from_str(input: &str) -> anyhow::Result<u64> {
+    Ok(total_fence_price(&Day12::parse(input)?))
+}
+
+pub fn puzzle02<P>(filename: P) -> anyhow::Result<u64>
+where
+    P: AsRef<Path>,
+{
+    puzzle02_from_reader(File::open(filename)?)
+}
+
+pub fn puzzle02_from_reader<R>(reader: R) -> anyhow::Result<u64>
+where
+    R: Read,
+{
+    puzzle02_from_str(&io::read_to_string(reader)?)
+}
+
+pub fn puzzle02_from_str(input: &str) -> anyhow::Result<u64> {
+    Ok(total_bulk_fence_price(&Day12::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test1() {
+        assert_eq!(crate::puzzle01("data/test_data_1").unwrap(), 1930)
+    }
+
+    #[test]
+    fn test1_from_str() {
+        let input = include_str!("../data/test_data_1");
+        assert_eq!(crate::puzzle01_from_str(input).unwrap(), 1930)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(crate::puzzle02("data/test_data_1").unwrap(), 1206)
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validator::validate;
+        use aoc_core::Problem;
+
+        assert_eq!(validate(include_str!("../data/test_data_1")), []);
+        assert_eq!(
+            validate("AAB\nAb\n"),
+            [
+                Problem::at_line(2, "row has 2 positions, the first row 3"),
+                Problem::at_line(2, "invalid character 'b' in column 2")
+            ]
+        );
+    }
+}
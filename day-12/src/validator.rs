@@ -0,0 +1,6 @@
+use aoc_core::{check_grid, Problem};
+
+/// A rectangular map of garden plots named by upper case letters.
+pub fn validate(input: &str) -> Vec<Problem> {
+    check_grid(input, |char| char.is_ascii_uppercase())
+}
@@ -0,0 +1,16 @@
+use day_12::{get_number_of_sides, get_perimeter, get_regions, total_fence_price};
+use grid::{Grid, Point};
+
+#[test]
+fn test_regions() {
+    let garden_map = Grid::parse("AAAA\nBBCD\nBBCC\nEEEC\n", Some).unwrap();
+    let regions = get_regions(&garden_map);
+    assert_eq!(regions.len(), 5);
+    let region_c = regions
+        .iter()
+        .find(|region| region.contains(&Point::new(2, 1)))
+        .unwrap();
+    assert_eq!((region_c.len(), get_perimeter(region_c)), (4, 10));
+    assert_eq!(get_number_of_sides(region_c), 8);
+    assert_eq!(total_fence_price(&garden_map), 140);
+}
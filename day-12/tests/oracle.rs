@@ -0,0 +1,94 @@
+use aoc_core::Solution;
+use day_12::{total_bulk_fence_price, total_fence_price, Day12};
+use proptest::prelude::*;
+
+const SIDES: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+fn plant(rows: &[Vec<char>], (x, y): (isize, isize)) -> Option<char> {
+    let row = rows.get(usize::try_from(y).ok()?)?;
+    row.get(usize::try_from(x).ok()?).copied()
+}
+
+// flood fills every region and counts its fences along the plots and its corners, a region
+// has as many sides as corners
+fn oracle_prices(rows: &[Vec<char>]) -> (u64, u64) {
+    let (width, height) = (rows[0].len(), rows.len());
+    let mut region_of = vec![vec![None; width]; height];
+    let mut regions: Vec<Vec<(isize, isize)>> = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            if region_of[y][x].is_some() {
+                continue;
+            }
+            let mut region = Vec::new();
+            let mut stack = vec![(x as isize, y as isize)];
+            region_of[y][x] = Some(regions.len());
+            while let Some(position) = stack.pop() {
+                region.push(position);
+                for (dx, dy) in SIDES {
+                    let next = (position.0 + dx, position.1 + dy);
+                    if plant(rows, next) == Some(rows[y][x]) {
+                        let seen = &mut region_of[next.1 as usize][next.0 as usize];
+                        if seen.is_none() {
+                            *seen = Some(regions.len());
+                            stack.push(next);
+                        }
+                    }
+                }
+            }
+            regions.push(region);
+        }
+    }
+
+    let mut price = 0;
+    let mut bulk_price = 0;
+    for region in &regions {
+        let same = |from: (isize, isize), (dx, dy): (isize, isize)| {
+            plant(rows, (from.0 + dx, from.1 + dy)) == plant(rows, from)
+        };
+        let mut perimeter = 0;
+        let mut corners = 0;
+        for position in region {
+            for (index, side) in SIDES.iter().enumerate() {
+                let next_side = SIDES[(index + 1) % 4];
+                let diagonal = (side.0 + next_side.0, side.1 + next_side.1);
+                if !same(*position, *side) {
+                    perimeter += 1;
+                }
+                let outer = !same(*position, *side) && !same(*position, next_side);
+                let inner = same(*position, *side)
+                    && same(*position, next_side)
+                    && !same(*position, diagonal);
+                if outer || inner {
+                    corners += 1;
+                }
+            }
+        }
+        price += region.len() as u64 * perimeter;
+        bulk_price += region.len() as u64 * corners;
+    }
+    (price, bulk_price)
+}
+
+fn garden() -> impl Strategy<Value = Vec<Vec<char>>> {
+    (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+        let plant = prop::sample::select(vec!['A', 'B', 'C']);
+        prop::collection::vec(prop::collection::vec(plant, width), height)
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_oracle(rows in garden()) {
+        let input: String = rows
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+        let garden_map = Day12::parse(&input).unwrap();
+        let (price, bulk_price) = oracle_prices(&rows);
+        prop_assert_eq!(total_fence_price(&garden_map), price);
+        prop_assert_eq!(total_bulk_fence_price(&garden_map), bulk_price);
+    }
+}
@@ -0,0 +1,26 @@
+use aoc_core::Solution;
+use day_12::{get_number_of_sides, get_perimeter, get_regions, Day12};
+
+#[test]
+fn test_regions() {
+    let garden_map = Day12::parse(include_str!("../data/test_data_1")).unwrap();
+    let regions: Vec<String> = get_regions(&garden_map)
+        .iter()
+        .map(|region| {
+            let first = region
+                .iter()
+                .min_by_key(|point| (point.y, point.x))
+                .unwrap();
+            format!(
+                "{} at ({}, {}): area {}, perimeter {}, sides {}",
+                garden_map[*first],
+                first.x,
+                first.y,
+                region.len(),
+                get_perimeter(region),
+                get_number_of_sides(region)
+            )
+        })
+        .collect();
+    insta::assert_snapshot!("regions", regions.join("\n"));
+}
@@ -0,0 +1,305 @@
+use crate::{Offset, Point};
+use std::ops::{Index, IndexMut};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    #[error("the grid is empty")]
+    Empty,
+    #[error("line {line} has {found} columns, expected {expected}")]
+    NotRectangular {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("unexpected character {char:?} at line {line}, column {column}")]
+    InvalidChar {
+        line: usize,
+        column: usize,
+        char: char,
+    },
+}
+
+/// A dense, rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, columns become rows.
+    pub fn transpose(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = rows.first().ok_or(GridError::Empty)?.len();
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(GridError::NotRectangular {
+                    line: y + 1,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses one row per line, mapping every character with `f`. `None` rejects the character.
+    /// Lines and columns in errors are counted from 1.
+    pub fn parse<F>(input: &str, mut f: F) -> Result<Self, GridError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, char)| {
+                        f(char).ok_or(GridError::InvalidChar {
+                            line: y + 1,
+                            column: x + 1,
+                            char,
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<T>>, GridError>>()?;
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The points whose value matches the predicate.
+    pub fn positions<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item = Point> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    pub fn find<P>(&self, mut predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    /// The horizontal and vertical neighbours inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours4()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The horizontal, vertical and diagonal neighbours inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours8()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The values from `start` stepping by `offset` until the border of the grid.
+    pub fn ray(&self, start: Point, offset: Offset) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            next: Some(start),
+            offset,
+        }
+    }
+
+    /// All maximal lines stepping by `offset`, each starting at the border of the grid.
+    pub fn lines(&self, offset: Offset) -> impl Iterator<Item = Ray<'_, T>> {
+        self.points()
+            .filter(move |point| {
+                !point
+                    .checked_add(-offset)
+                    .is_some_and(|previous| self.contains(previous))
+            })
+            .map(move |start| self.ray(start, offset))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.width).map(|x| self.ray(Point::new(x, 0), Offset::DOWN))
+    }
+
+    /// The lines running from the top left to the bottom right.
+    pub fn diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        self.lines(Offset::DOWN_RIGHT)
+    }
+
+    /// The lines running from the top right to the bottom left.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        self.lines(Offset::DOWN_LEFT)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+/// Iterator over the values along a straight line, see [`Grid::ray`].
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    next: Option<Point>,
+    offset: Offset,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let point = self.next?;
+        let value = self.grid.get(point)?;
+        self.next = point.checked_add(self.offset);
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, GridError, Offset, Point};
+
+    fn digits(input: &str) -> Result<Grid<u32>, GridError> {
+        Grid::parse(input, |char| char.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.find(|value| *value == 5), Some(Point::new(1, 1)));
+        assert_eq!(
+            digits("12\n3x\n"),
+            Err(GridError::InvalidChar {
+                line: 2,
+                column: 2,
+                char: 'x'
+            })
+        );
+        assert_eq!(
+            digits("12\n345\n"),
+            Err(GridError::NotRectangular {
+                line: 2,
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(digits(""), Err(GridError::Empty));
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = digits("123\n456\n").unwrap();
+        let lines = |rays: Vec<Vec<&u32>>| -> Vec<Vec<u32>> {
+            rays.into_iter()
+                .map(|ray| ray.into_iter().copied().collect())
+                .collect()
+        };
+        assert_eq!(
+            lines(grid.columns().map(Iterator::collect).collect()),
+            [vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(
+            lines(grid.diagonals().map(Iterator::collect).collect()),
+            [vec![1, 5], vec![2, 6], vec![3], vec![4]]
+        );
+        assert_eq!(
+            lines(grid.anti_diagonals().map(Iterator::collect).collect()),
+            [vec![1], vec![2, 4], vec![3, 5], vec![6]]
+        );
+        assert_eq!(
+            grid.ray(Point::new(2, 1), Offset::LEFT)
+                .copied()
+                .collect::<Vec<_>>(),
+            [6, 5, 4]
+        );
+        assert_eq!(
+            grid.transpose().rows().collect::<Vec<_>>(),
+            [[1, 4], [2, 5], [3, 6]]
+        );
+        assert_eq!(grid.neighbours8(Point::new(0, 1)).count(), 3);
+    }
+}
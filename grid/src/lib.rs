@@ -0,0 +1,5 @@
+mod grid;
+mod point;
+
+pub use grid::{Grid, GridError, Ray};
+pub use point::{Direction, Offset, Point};
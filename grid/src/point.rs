@@ -0,0 +1,206 @@
+use std::{
+    fmt::{self, Debug},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// A position on a grid. `x` is the column and `y` the row, growing to the right and downwards.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Debug for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(x: {}, y: {})", self.x, self.y)
+    }
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    /// Moves the point by an offset, `None` if it would leave the non-negative quadrant.
+    pub fn checked_add(&self, offset: Offset) -> Option<Point> {
+        Some(Point::new(
+            self.x.checked_add_signed(offset.dx)?,
+            self.y.checked_add_signed(offset.dy)?,
+        ))
+    }
+
+    pub fn step(&self, direction: Direction) -> Option<Point> {
+        self.checked_add(direction.offset())
+    }
+
+    /// The offset leading from this point to `other`.
+    pub fn offset_to(&self, other: Point) -> Offset {
+        Offset::new(
+            other.x as isize - self.x as isize,
+            other.y as isize - self.y as isize,
+        )
+    }
+
+    /// The horizontal and vertical neighbours. Neighbours outside of any grid are left out.
+    pub fn neighbours4(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        Offset::NEIGHBOURS_4
+            .into_iter()
+            .filter_map(move |offset| point.checked_add(offset))
+    }
+
+    /// The horizontal, vertical and diagonal neighbours.
+    pub fn neighbours8(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        Offset::NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |offset| point.checked_add(offset))
+    }
+}
+
+/// A signed distance between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Offset {
+    pub dx: isize,
+    pub dy: isize,
+}
+
+impl Offset {
+    pub const UP: Offset = Offset::new(0, -1);
+    pub const DOWN: Offset = Offset::new(0, 1);
+    pub const LEFT: Offset = Offset::new(-1, 0);
+    pub const RIGHT: Offset = Offset::new(1, 0);
+    pub const UP_LEFT: Offset = Offset::new(-1, -1);
+    pub const UP_RIGHT: Offset = Offset::new(1, -1);
+    pub const DOWN_LEFT: Offset = Offset::new(-1, 1);
+    pub const DOWN_RIGHT: Offset = Offset::new(1, 1);
+
+    pub const NEIGHBOURS_4: [Offset; 4] = [Offset::LEFT, Offset::RIGHT, Offset::UP, Offset::DOWN];
+    pub const DIAGONALS: [Offset; 4] = [
+        Offset::UP_LEFT,
+        Offset::UP_RIGHT,
+        Offset::DOWN_LEFT,
+        Offset::DOWN_RIGHT,
+    ];
+    pub const NEIGHBOURS_8: [Offset; 8] = [
+        Offset::LEFT,
+        Offset::RIGHT,
+        Offset::UP,
+        Offset::DOWN,
+        Offset::UP_LEFT,
+        Offset::UP_RIGHT,
+        Offset::DOWN_LEFT,
+        Offset::DOWN_RIGHT,
+    ];
+
+    pub const fn new(dx: isize, dy: isize) -> Self {
+        Offset { dx, dy }
+    }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, other: Offset) -> Offset {
+        Offset::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl Sub for Offset {
+    type Output = Offset;
+
+    fn sub(self, other: Offset) -> Offset {
+        Offset::new(self.dx - other.dx, self.dy - other.dy)
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Offset {
+        Offset::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Offset;
+
+    fn mul(self, factor: isize) -> Offset {
+        Offset::new(self.dx * factor, self.dy * factor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(&self) -> Offset {
+        match self {
+            Direction::Up => Offset::UP,
+            Direction::Right => Offset::RIGHT,
+            Direction::Down => Offset::DOWN,
+            Direction::Left => Offset::LEFT,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, Offset, Point};
+
+    #[test]
+    fn test_offsets() {
+        let point = Point::new(2, 5);
+        let other = Point::new(4, 1);
+        let offset = point.offset_to(other);
+        assert_eq!(offset, Offset::new(2, -4));
+        assert_eq!(other.checked_add(offset), None);
+        assert_eq!(point.checked_add(offset * 2), None);
+        assert_eq!(point.checked_add(-offset), Some(Point::new(0, 9)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(Point::new(0, 0).neighbours4().count(), 2);
+        assert_eq!(Point::new(0, 3).neighbours8().count(), 5);
+        assert_eq!(Point::new(1, 1).neighbours8().count(), 8);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Point::new(3, 0).step(Direction::Up), None);
+    }
+}